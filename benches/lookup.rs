@@ -0,0 +1,97 @@
+//! Compares the indexed lookups of the content lists against the linear
+//! searches they replaced, for lists of thousands of items.
+//!
+//! Run with `cargo bench --bench lookup`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use kira_me_website::{Area, AreaList, Creation, CreationList, Get, Indexed, KiraDate, Localised};
+
+const SIZES: [usize; 3] = [100, 1_000, 5_000];
+
+fn creations(size: usize) -> Vec<Creation> {
+    (0..size)
+        .map(|index| Creation {
+            name: format!("Creation {index}"),
+            completed: KiraDate::new(2024, 1, 1),
+            areas: vec![],
+            tools: vec![],
+            skills: vec![],
+            description: Localised::default(),
+            media: vec![],
+        })
+        .collect()
+}
+
+fn areas(size: usize) -> Vec<Area> {
+    (0..size)
+        .map(|index| Area(Box::leak(format!("area/{index}").into_boxed_str())))
+        .collect()
+}
+
+/// Looks up the last creation, the worst case for a linear search.
+fn creation_lookups(c: &mut Criterion) {
+    let mut group = c.benchmark_group("creation lookup");
+
+    for size in SIZES {
+        let items = creations(size);
+        let list = CreationList::new(items.clone());
+        let name = format!("Creation {}", size - 1);
+
+        group.bench_with_input(BenchmarkId::new("linear", size), &name, |b, name| {
+            b.iter(|| {
+                items
+                    .iter()
+                    .find(|creation| creation.name == *name)
+                    .cloned()
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("indexed", size), &name, |b, name| {
+            b.iter(|| list.get(black_box(name)));
+        });
+        group.bench_with_input(BenchmarkId::new("ignoring case", size), &name, |b, name| {
+            let name = name.to_uppercase();
+            b.iter(|| list.get_ignoring_case(black_box(&name)).cloned());
+        });
+    }
+
+    group.finish();
+}
+
+/// Looks up the last area, comparing against the old search, which also
+/// allocated a string for every area it passed.
+fn area_lookups(c: &mut Criterion) {
+    let mut group = c.benchmark_group("area lookup");
+
+    for size in SIZES {
+        let items = areas(size);
+        let list = AreaList::from(items.clone());
+        let name = format!("area/{}", size - 1);
+
+        group.bench_with_input(BenchmarkId::new("linear", size), &name, |b, name| {
+            b.iter(|| items.iter().find(|area| area.to_string() == *name).copied());
+        });
+        group.bench_with_input(BenchmarkId::new("indexed", size), &name, |b, name| {
+            b.iter(|| list.get(black_box(name)));
+        });
+    }
+
+    group.finish();
+}
+
+/// Builds the lists, the one-off cost of indexing them.
+fn list_construction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("creation list construction");
+
+    for size in SIZES {
+        let items = creations(size);
+
+        group.bench_with_input(BenchmarkId::from_parameter(size), &items, |b, items| {
+            b.iter(|| CreationList::new(black_box(items.clone())));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, creation_lookups, area_lookups, list_construction);
+criterion_main!(benches);
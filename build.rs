@@ -0,0 +1,25 @@
+//! Records when and from which commit the site was built, for the footer.
+
+use std::process::Command;
+
+fn main() {
+    let commit = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map_or_else(
+            || String::from("unknown"),
+            |commit| commit.trim().to_string(),
+        );
+    let now = chrono::Utc::now();
+
+    println!("cargo:rustc-env=KIRA_BUILD_COMMIT={commit}");
+    println!("cargo:rustc-env=KIRA_BUILD_DATE={}", now.format("%Y-%m-%d"));
+    println!("cargo:rustc-env=KIRA_BUILD_YEAR={}", now.format("%Y"));
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs");
+    // So the date is refreshed by uncommitted changes too.
+    println!("cargo:rerun-if-changed=src");
+}
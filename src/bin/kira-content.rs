@@ -0,0 +1,268 @@
+//! Native tooling for the site's content.
+//!
+//! Usage:
+//!
+//! ```text
+//! kira-content resume [PATH]     Write the JSON Resume export to PATH, or stdout.
+//! kira-content check [--json]     Report problems with the content, and exit
+//!                                 with an error if there are any.
+//! kira-content new KIND [FLAGS]   Add a new skill, achievement, creation,
+//!                                 project, article, education or experience
+//!                                 to `src/data.rs`, asking for any details
+//!                                 not given by flags.
+//! kira-content images DIR         Build the variants of the images content
+//!                                 uses into `images/` in the target
+//!                                 directory, update `media/manifest.json`,
+//!                                 and copy the variants to DIR.
+//! ```
+//!
+//! Flags for `new`, where the reference flags can be repeated:
+//!
+//! ```text
+//! --name NAME         The name, title of an article, qualification of an
+//!                     education or role of an experience.
+//! --date YYYY-MM-DD   When it was started, completed or published; today if not
+//!                     given.
+//! --area AREA         An area it's in.
+//! --tool TOOL         A tool it used.
+//! --skill SKILL       A skill it used.
+//! ```
+
+use color_eyre::eyre::{bail, eyre, Result};
+use kira_me_website::{
+    check::{check, Issue},
+    data::Portfolio,
+    images::pipeline,
+    resume::Resume,
+    scaffold::{insert, ContentKind, Draft},
+    KiraDate, MediaKind,
+};
+use std::{
+    env, fs,
+    io::{self, BufRead, Write},
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// The file content is written in.
+const DATA_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/data.rs");
+
+/// The manifest of the images' variants, compiled into the site.
+const MANIFEST_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/media/manifest.json");
+
+const USAGE: &str = "Usage: kira-content resume [PATH] | kira-content check [--json] | \
+                     kira-content new KIND [FLAGS] | kira-content images DIR";
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<&str>>()
+        .as_slice()
+    {
+        ["resume"] => println!("{}", Resume::from(&portfolio()?).to_json()?),
+        ["resume", path] => fs::write(path, Resume::from(&portfolio()?).to_json()?)?,
+        ["check"] => report(&issues(), false)?,
+        ["check", "--json"] => report(&issues(), true)?,
+        ["new", kind, flags @ ..] => scaffold(kind, flags)?,
+        ["images", out] => images(Path::new(out))?,
+        _ => bail!(USAGE),
+    }
+
+    Ok(())
+}
+
+/// Returns the portfolio, or fails with every error in its content.
+fn portfolio() -> Result<Portfolio> {
+    Portfolio::load().map_err(|errors| {
+        eyre!(
+            "The content has {} error(s):\n{}",
+            errors.len(),
+            errors
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join("\n")
+        )
+    })
+}
+
+/// Returns every problem with the content, including the broken references
+/// that would stop it loading.
+fn issues() -> Vec<Issue> {
+    let (portfolio, errors) = Portfolio::load_unchecked();
+
+    errors
+        .into_iter()
+        .map(Issue::from)
+        .chain(check(&portfolio))
+        .collect()
+}
+
+/// Writes the issues to stdout, as JSON or one per line, and fails if there
+/// are any so that CI catches them.
+fn report(issues: &[Issue], json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(issues)?);
+    } else {
+        for issue in issues {
+            println!("{issue}");
+        }
+    }
+
+    if !issues.is_empty() {
+        bail!("Found {} content issue(s)", issues.len());
+    }
+
+    Ok(())
+}
+
+/// Returns where the images' variants are built, which outlives each site
+/// build so that they're only built again when their originals change: the
+/// `images` directory in `CARGO_TARGET_DIR`, or else in the target directory
+/// this binary was built in.
+fn image_cache() -> Result<PathBuf> {
+    let target = match env::var_os("CARGO_TARGET_DIR") {
+        Some(target) => PathBuf::from(target),
+        // The binary is in the profile's directory, like `target/release`.
+        None => env::current_exe()?
+            .parent()
+            .and_then(Path::parent)
+            .map(Path::to_path_buf)
+            .ok_or_else(|| eyre!("Couldn't find the target directory"))?,
+    };
+
+    Ok(target.join("images"))
+}
+
+/// Builds the variants of every image the content uses into the cache,
+/// updates the manifest if they've changed, so the site is only rebuilt when
+/// it needs to be, and copies the variants to `out`.
+fn images(out: &Path) -> Result<()> {
+    let portfolio = portfolio()?;
+    let sources = portfolio
+        .media()
+        .filter(|media| media.kind == MediaKind::Image)
+        .map(|media| media.src.as_str());
+    let cache = image_cache()?;
+
+    let manifest = pipeline::build(Path::new(env!("CARGO_MANIFEST_DIR")), sources, &cache)?;
+    let json = manifest.to_json()?;
+
+    if fs::read_to_string(MANIFEST_PATH).ok().as_ref() != Some(&json) {
+        fs::write(MANIFEST_PATH, json)?;
+    }
+
+    pipeline::copy(&manifest, &cache, out)?;
+
+    Ok(())
+}
+
+/// Adds a new entry of the named kind to the data file, from the flags or,
+/// if no name is given, from answers to prompts.
+fn scaffold(kind: &str, flags: &[&str]) -> Result<()> {
+    let Some(kind) = ContentKind::from_name(kind) else {
+        bail!(
+            "Unknown kind `{kind}`, expected one of: {}",
+            ContentKind::ALL.map(|kind| kind.to_string()).join(", ")
+        );
+    };
+
+    let mut name = None;
+    let mut date = None;
+    let mut areas = Vec::new();
+    let mut tools = Vec::new();
+    let mut skills = Vec::new();
+
+    for pair in flags.chunks(2) {
+        match pair {
+            ["--name", value] => name = Some((*value).to_string()),
+            ["--date", value] => date = Some(parse_date(value)?),
+            ["--area", value] => areas.push((*value).to_string()),
+            ["--tool", value] => tools.push((*value).to_string()),
+            ["--skill", value] => skills.push((*value).to_string()),
+            _ => bail!(USAGE),
+        }
+    }
+
+    let name = if let Some(name) = name {
+        name
+    } else {
+        let name = prompt("Name")?;
+        if date.is_none() {
+            let answer = prompt("Date (YYYY-MM-DD, blank for today)")?;
+            if !answer.is_empty() {
+                date = Some(parse_date(&answer)?);
+            }
+        }
+        for (field, references) in [
+            ("areas", &mut areas),
+            ("tools", &mut tools),
+            ("skills", &mut skills),
+        ] {
+            if references.is_empty() && kind.supports(field) {
+                references.extend(
+                    prompt(&format!("{field} (comma-separated)"))?
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|reference| !reference.is_empty())
+                        .map(ToString::to_string),
+                );
+            }
+        }
+        name
+    };
+
+    let draft = Draft::new(
+        kind,
+        &name,
+        date.unwrap_or_else(KiraDate::today),
+        areas,
+        tools,
+        skills,
+        &portfolio()?,
+    )?;
+
+    let Some(source) = insert(&fs::read_to_string(DATA_PATH)?, &draft) else {
+        bail!("Couldn't find the {} in {DATA_PATH}", kind.list_type());
+    };
+    fs::write(DATA_PATH, source)?;
+
+    if !Command::new("rustfmt")
+        .args(["--edition", "2021", DATA_PATH])
+        .status()
+        .is_ok_and(|status| status.success())
+    {
+        eprintln!("Couldn't run rustfmt; run `cargo fmt` to tidy {DATA_PATH}");
+    }
+
+    println!(
+        "Added {kind} `{}` (slug `{}`) to {DATA_PATH}",
+        draft.name,
+        draft.slug()
+    );
+
+    Ok(())
+}
+
+fn parse_date(date: &str) -> Result<KiraDate> {
+    match KiraDate::from_iso_string(date) {
+        Some(date) => Ok(date),
+        None => bail!("`{date}` isn't a valid YYYY-MM-DD date"),
+    }
+}
+
+/// Asks a question on stdout and returns the trimmed answer from stdin.
+fn prompt(question: &str) -> Result<String> {
+    print!("{question}: ");
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+
+    Ok(answer.trim().to_string())
+}
@@ -0,0 +1,191 @@
+use crate::{data::Portfolio, Area, Get, Locale, Route};
+use serde_json::json;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Experience, ExperienceKind, ExperienceList, KiraDate, Localised};
+    use rstest::rstest;
+
+    fn portfolio() -> Portfolio {
+        let mut portfolio = Portfolio::load().expect("Failed to load portfolio");
+        portfolio.experience = ExperienceList::new(vec![Experience {
+            role: "Developer".to_string(),
+            organisation: "Example Ltd".to_string(),
+            kind: ExperienceKind::Work,
+            started: KiraDate::new(2023, 6, 1),
+            ended: None,
+            areas: vec![],
+            skills: vec![],
+            description: Localised::from("A role."),
+        }]);
+        portfolio
+    }
+
+    #[rstest]
+    #[case(Route::Home, vec![])]
+    #[case(Route::Skills, vec![])]
+    #[case(
+        Route::Skill { skill: String::from("Rust") },
+        vec!["Home", "Skills", "Rust"],
+    )]
+    #[case(
+        Route::Role { role: String::from("Developer") },
+        vec!["Home", "Experience", "Developer"],
+    )]
+    #[case(
+        Route::Project { project: String::from("Web Profile") },
+        vec!["Home", "Projects", "Web Profile"],
+    )]
+    #[case(
+        Route::Area { area: String::from("development/web") },
+        vec!["Home", "development", "web"],
+    )]
+    #[case(
+        Route::Area { area: String::from("graphics/digital") },
+        vec!["Home", "digital"],
+    )]
+    #[case(
+        Route::Creation { creation: String::from("Missing") },
+        vec!["Home", "Creations", "Not Found"],
+    )]
+    fn trail_works_as_expected(#[case] route: Route, #[case] expected: Vec<&str>) {
+        let titles = trail(&route, &portfolio())
+            .into_iter()
+            .map(|crumb| crumb.title)
+            .collect::<Vec<String>>();
+
+        assert_eq!(titles, expected);
+    }
+
+    #[rstest]
+    fn trail_to_json_ld_numbers_items_from_one() {
+        let portfolio = Portfolio::load().expect("Failed to load portfolio");
+        let route = Route::Skill {
+            skill: String::from("Rust"),
+        };
+        let json = trail_to_json_ld(&trail(&route, &portfolio), portfolio.contact.url);
+
+        assert_eq!(json["@type"], "BreadcrumbList");
+        assert_eq!(json["itemListElement"][0]["position"], 1);
+        assert_eq!(
+            json["itemListElement"][1]["item"],
+            "https://queenkirathepansexualpixie.github.io/skills"
+        );
+    }
+}
+
+/// A step in a breadcrumb trail.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Crumb {
+    pub title: String,
+    pub route: Route,
+}
+
+/// Returns the trail from the home page to `route`, using the content's
+/// display names, or an empty trail for top-level pages.
+pub fn trail(route: &Route, portfolio: &Portfolio) -> Vec<Crumb> {
+    let section = route.section();
+
+    if section == *route {
+        return Vec::new();
+    }
+
+    let mut crumbs = vec![Crumb {
+        title: Route::Home.title(),
+        route: Route::Home,
+    }];
+
+    if section != Route::Home {
+        crumbs.push(Crumb {
+            title: section.title(),
+            route: section,
+        });
+    }
+
+    // An area's trail passes through the areas it's part of, where they have
+    // pages of their own.
+    if let Route::Area { area } = route {
+        crumbs.extend(
+            portfolio
+                .areas
+                .get(area)
+                .into_iter()
+                .flat_map(Area::ancestors)
+                .filter_map(|ancestor| portfolio.areas.get(ancestor.0))
+                .map(|ancestor| Crumb {
+                    title: ancestor.short_name().to_string(),
+                    route: Route::Area {
+                        area: ancestor.0.to_string(),
+                    },
+                }),
+        );
+    }
+
+    crumbs.push(Crumb {
+        title: portfolio
+            .title_of(route)
+            .unwrap_or_else(|| Locale::current().messages().not_found.to_string()),
+        route: route.clone(),
+    });
+
+    crumbs
+}
+
+/// Returns the trail as schema.org `BreadcrumbList` JSON-LD structured data,
+/// with addresses in the current locale, relative to `base_url`.
+pub fn trail_to_json_ld(trail: &[Crumb], base_url: &str) -> serde_json::Value {
+    json!({
+        "@context": "https://schema.org",
+        "@type": "BreadcrumbList",
+        "itemListElement": trail
+            .iter()
+            .enumerate()
+            .map(|(index, crumb)| json!({
+                "@type": "ListItem",
+                "position": index + 1,
+                "name": crumb.title,
+                "item": format!(
+                    "{}{}",
+                    base_url.trim_end_matches('/'),
+                    Locale::current().path_to(&crumb.route)
+                ),
+            }))
+            .collect::<Vec<serde_json::Value>>(),
+    })
+}
+
+#[derive(Properties, PartialEq)]
+pub struct BreadcrumbsProperties {
+    pub portfolio: Rc<Portfolio>,
+}
+
+/// The trail from the home page to the current route, shown on nested pages.
+#[function_component(Breadcrumbs)]
+pub fn breadcrumbs(BreadcrumbsProperties { portfolio }: &BreadcrumbsProperties) -> Html {
+    let route = use_route::<Route>();
+    let crumbs = route.map_or_else(Vec::new, |route| trail(&route, portfolio));
+
+    let Some((current, parents)) = crumbs.split_last() else {
+        return html! {};
+    };
+
+    html! {
+        <nav class={"breadcrumbs"} aria-label={Locale::current().messages().breadcrumb}>
+            <ol>
+                {parents.iter().map(|crumb| html!{
+                    <li>
+                        <Link<Route> to={crumb.route.clone()}>{crumb.title.clone()}</Link<Route>>
+                    </li>
+                }).collect::<Html>()}
+                <li aria-current={"page"}>{current.title.clone()}</li>
+            </ol>
+            <script type={"application/ld+json"}>
+                {trail_to_json_ld(&crumbs, portfolio.contact.url).to_string()}
+            </script>
+        </nav>
+    }
+}
@@ -0,0 +1,570 @@
+//! Validation of the site's content, for catching mistakes before they reach
+//! the browser.
+//!
+//! [`check`] reports:
+//!
+//! - items sharing an [`Id`](crate::Id) within a list, which Yew would give
+//!   the same `key`, and so mix up their elements
+//! - items sharing a name or title within a list, as [`Get::get`] only ever
+//!   finds the first of them
+//! - items whose names differ only in case, or share a [`slug`](crate::slug),
+//!   like "C++" and "C#", as a [`Lookup`] only ever finds the first of them
+//! - media attached to an item more than once, which its gallery would show
+//!   twice, and releases of a project sharing a version, which Yew would give
+//!   the same `key`
+//! - invalid [`KiraDate`]s, like the 31st of February
+//! - references to [`Area`]s, [`Tool`]s and [`Skill`]s that aren't in the
+//!   portfolio's lists
+//! - empty text, and placeholder text like `<NAME>`
+//!
+//! An article's content is markup rather than text, so it isn't checked.
+
+use crate::{
+    data::Portfolio, slug, Area, ContentError, ContentItem, ContentList, Get, KiraDate, Locale,
+    Localised, Lookup, Media, NotFoundValue, Skill, Tool,
+};
+use serde::Serialize;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    rc::Rc,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Achievement, AchievementList, Creation, CreationList};
+    use rstest::rstest;
+
+    fn achievement(name: &str) -> Achievement {
+        Achievement {
+            name: name.to_string(),
+            completed: KiraDate::new(2023, 5, 24),
+            areas: vec![],
+            tools: vec![],
+            skills: vec![],
+            description: Localised::from("An achievement."),
+            media: vec![],
+        }
+    }
+
+    fn kinds(portfolio: &Portfolio) -> Vec<IssueKind> {
+        check(portfolio)
+            .into_iter()
+            .map(|issue| issue.kind)
+            .collect()
+    }
+
+    #[rstest]
+    #[case("<NAME>", true)]
+    #[case("Written by <AUTHOR NAME>.", true)]
+    #[case("<ERR: Area not found>", false)]
+    #[case("1 < 2 > 0", false)]
+    #[case("<>", false)]
+    #[case("A personal profile website.", false)]
+    fn has_placeholder_works_as_expected(#[case] text: &str, #[case] expected: bool) {
+        assert_eq!(has_placeholder(text), expected);
+    }
+
+    #[rstest]
+    fn check_finds_duplicate_ids_and_names() {
+        let mut portfolio = Portfolio::load().expect("Failed to load portfolio");
+        portfolio.achievements =
+            AchievementList::new(vec![achievement("First"), achievement("First")]);
+
+        assert_eq!(
+            kinds(&portfolio)
+                .into_iter()
+                .filter(|kind| matches!(kind, IssueKind::DuplicateId | IssueKind::DuplicateName))
+                .collect::<Vec<IssueKind>>(),
+            vec![IssueKind::DuplicateName]
+        );
+    }
+
+    #[rstest]
+    fn check_finds_names_differing_only_in_case_or_punctuation() {
+        let mut portfolio = Portfolio::load().expect("Failed to load portfolio");
+        portfolio.achievements = AchievementList::new(vec![
+            achievement("C++"),
+            achievement("C#"),
+            achievement("c++"),
+            achievement("First"),
+        ]);
+
+        assert_eq!(
+            check(&portfolio)
+                .into_iter()
+                .filter(|issue| issue.kind == IssueKind::DuplicateSlug)
+                .map(|issue| issue.message)
+                .collect::<Vec<String>>(),
+            vec![
+                "has the same slug as `C++`, `c`, so only the first can be looked up by slug"
+                    .to_string(),
+                "differs from `C++` only in case, so only the first can be looked up ignoring case"
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn check_finds_duplicate_keys() {
+        let mut portfolio = Portfolio::load().expect("Failed to load portfolio");
+        let media = Media::image("/media/award.png", Localised::from("An award."));
+        portfolio.achievements = AchievementList::new(vec![Achievement {
+            media: vec![media.clone(), media],
+            ..achievement("First")
+        }]);
+
+        assert!(kinds(&portfolio).contains(&IssueKind::DuplicateKey));
+    }
+
+    #[rstest]
+    fn check_finds_invalid_dates() {
+        let mut portfolio = Portfolio::load().expect("Failed to load portfolio");
+        portfolio.achievements = AchievementList::new(vec![Achievement {
+            completed: KiraDate::new(2023, 2, 31),
+            ..achievement("First")
+        }]);
+
+        assert!(kinds(&portfolio).contains(&IssueKind::InvalidDate));
+    }
+
+    #[rstest]
+    fn check_finds_missing_references() {
+        let mut portfolio = Portfolio::load().expect("Failed to load portfolio");
+        portfolio.creations = CreationList::new(vec![Creation {
+            name: "Creation".to_string(),
+            completed: KiraDate::new(2023, 9, 16),
+            areas: vec![Rc::new(Area("nowhere"))],
+            tools: vec![Rc::new(Tool("Notepad")), Rc::new(Tool::not_found())],
+            skills: vec![
+                Rc::new(Skill {
+                    name: "Juggling".to_string(),
+                    ..Skill::not_found()
+                }),
+                Rc::new(Skill::not_found()),
+            ],
+            description: Localised::from("A creation."),
+            media: vec![],
+        }]);
+
+        // The "not found" values stand in for references that already failed
+        // to load, which are reported with the names they were looked up by.
+        assert_eq!(
+            kinds(&portfolio)
+                .into_iter()
+                .filter(|kind| *kind == IssueKind::MissingReference)
+                .count(),
+            3
+        );
+    }
+
+    #[rstest]
+    fn check_finds_empty_and_placeholder_text() {
+        let mut portfolio = Portfolio::load().expect("Failed to load portfolio");
+        portfolio.achievements = AchievementList::new(vec![Achievement {
+            description: Localised::from(" "),
+            ..achievement("<NAME>")
+        }]);
+
+        let kinds = kinds(&portfolio);
+
+        assert!(kinds.contains(&IssueKind::EmptyText));
+        assert!(kinds.contains(&IssueKind::Placeholder));
+    }
+
+    #[rstest]
+    fn check_finds_media_without_alt_text() {
+        let mut portfolio = Portfolio::load().expect("Failed to load portfolio");
+        portfolio.achievements = AchievementList::new(vec![Achievement {
+            media: vec![Media::image("/media/award.png", Localised::from(""))],
+            ..achievement("First")
+        }]);
+
+        assert!(kinds(&portfolio).contains(&IssueKind::EmptyText));
+    }
+
+    #[rstest]
+    fn issue_from_content_error_works_as_expected() {
+        let issue = Issue::from(ContentError::Missing {
+            list: "areas",
+            name: "nowhere".to_string(),
+            from_list: "creations",
+            from: "Web Profile".to_string(),
+        });
+
+        assert_eq!(issue.kind, IssueKind::MissingReference);
+        assert_eq!(
+            issue.to_string(),
+            "creations `Web Profile`: `Web Profile` in the creations references `nowhere`, which isn't in the areas"
+        );
+    }
+
+    #[rstest]
+    fn loaded_portfolio_has_no_issues() {
+        assert_eq!(
+            check(&Portfolio::load().expect("Failed to load portfolio")),
+            vec![]
+        );
+    }
+}
+
+/// The kinds of problem [`check`] finds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueKind {
+    DuplicateId,
+    DuplicateName,
+    /// Items whose names differ only in case, or share a slug.
+    DuplicateSlug,
+    DuplicateKey,
+    InvalidDate,
+    MissingReference,
+    EmptyText,
+    Placeholder,
+}
+
+/// A problem with a content item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Issue {
+    pub kind: IssueKind,
+    /// The list the item is in, like `"skills"`.
+    pub list: &'static str,
+    /// The item's [`Id`](crate::Id), or `None` for items without one, like
+    /// [`Area`]s.
+    pub id: Option<String>,
+    /// The item's name or title.
+    pub name: String,
+    pub message: String,
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} `{}`: {}", self.list, self.name, self.message)
+    }
+}
+
+impl From<ContentError> for Issue {
+    /// Returns the issue for a broken piece of content found by
+    /// [`Portfolio::load`] or [`Portfolio::load_unchecked`].
+    fn from(error: ContentError) -> Self {
+        let (kind, list, name) = match &error {
+            ContentError::Missing {
+                from_list, from, ..
+            } => (IssueKind::MissingReference, from_list, from),
+            ContentError::Duplicate { list, name } => (IssueKind::DuplicateName, list, name),
+            ContentError::InvalidDate { list, name, .. } => (IssueKind::InvalidDate, list, name),
+        };
+
+        Self {
+            kind,
+            list,
+            id: None,
+            name: name.clone(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// The parts of a content item that get checked, so that every list can be
+/// checked the same way.
+struct Entry {
+    list: &'static str,
+    id: Option<String>,
+    name: String,
+    dates: Vec<(&'static str, KiraDate)>,
+    /// The keys of the item's parts that are rendered side by side, like its
+    /// media.
+    keys: Vec<(&'static str, String)>,
+    texts: Vec<(String, String)>,
+    areas: Vec<Area>,
+    tools: Vec<Tool>,
+    skills: Vec<Rc<Skill>>,
+}
+
+impl Entry {
+    fn new(list: &'static str, id: Option<String>, name: &str) -> Self {
+        Self {
+            list,
+            id,
+            name: name.to_string(),
+            dates: Vec::new(),
+            keys: Vec::new(),
+            texts: Vec::new(),
+            areas: Vec::new(),
+            tools: Vec::new(),
+            skills: Vec::new(),
+        }
+    }
+
+    /// Returns the entry for a content item, from the parts every
+    /// [`ContentItem`] has.
+    fn from_item<T: ContentItem>(item: &T) -> Self {
+        Self {
+            list: T::LIST,
+            id: Some(item.id().to_string()),
+            name: item.name().to_string(),
+            dates: item.dates(),
+            keys: item.keys(),
+            texts: item
+                .texts()
+                .iter()
+                .flat_map(|(field, text)| localised(field, text))
+                .chain(media_texts(item.media()))
+                .collect(),
+            areas: item.areas(),
+            tools: item.tools(),
+            skills: item.skills().to_vec(),
+        }
+    }
+
+    fn issue(&self, kind: IssueKind, message: String) -> Issue {
+        Issue {
+            kind,
+            list: self.list,
+            id: self.id.clone(),
+            name: self.name.clone(),
+            message,
+        }
+    }
+}
+
+/// Returns every problem with the portfolio's content, list by list.
+pub fn check(portfolio: &Portfolio) -> Vec<Issue> {
+    let lists = entries(portfolio);
+    let mut issues = Vec::new();
+
+    for entries in &lists {
+        issues.extend(duplicates(entries));
+
+        for entry in entries {
+            issues.extend(duplicate_keys(entry));
+            issues.extend(invalid_dates(entry));
+            issues.extend(missing_references(entry, portfolio));
+            issues.extend(bad_text(entry));
+        }
+    }
+
+    issues
+}
+
+/// Returns the portfolio's items, grouped by list.
+fn entries(portfolio: &Portfolio) -> Vec<Vec<Entry>> {
+    vec![
+        portfolio
+            .areas
+            .iter()
+            .map(|area| Entry::new("areas", None, area.0))
+            .collect(),
+        portfolio
+            .tools
+            .iter()
+            .map(|tool| Entry::new("tools", None, tool.0))
+            .collect(),
+        list_entries(&portfolio.skills),
+        list_entries(&portfolio.achievements),
+        list_entries(&portfolio.creations),
+        list_entries(&portfolio.projects),
+        list_entries(&portfolio.articles),
+        list_entries(&portfolio.education),
+        list_entries(&portfolio.experience),
+    ]
+}
+
+/// Returns the entries for the items in a content list.
+fn list_entries<T: ContentItem>(list: &ContentList<T>) -> Vec<Entry> {
+    list.iter().map(Entry::from_item).collect()
+}
+
+/// Returns a field's text in each locale, skipping untranslated locales so
+/// that problems with the default text are only reported once.
+fn localised(field: &str, text: &Localised) -> Vec<(String, String)> {
+    let default = text.get(Locale::default());
+
+    Locale::ALL
+        .iter()
+        .filter(|locale| **locale == Locale::default() || text.get(**locale) != default)
+        .map(|locale| {
+            let field = if *locale == Locale::default() {
+                field.to_string()
+            } else {
+                format!("{field} ({})", locale.code())
+            };
+
+            (field, text.get(*locale).to_string())
+        })
+        .collect()
+}
+
+/// Returns the alt text and captions of an item's media, in each locale.
+fn media_texts(media: &[Media]) -> Vec<(String, String)> {
+    media
+        .iter()
+        .flat_map(|media| {
+            localised("media alt", &media.alt).into_iter().chain(
+                media
+                    .caption
+                    .iter()
+                    .flat_map(|caption| localised("media caption", caption)),
+            )
+        })
+        .collect()
+}
+
+fn duplicates(entries: &[Entry]) -> Vec<Issue> {
+    let mut ids: HashMap<&str, &str> = HashMap::new();
+    let mut names: HashSet<&str> = HashSet::new();
+    let lookup: Lookup<Entry> = Lookup::new(entries.iter().map(|entry| entry.name.as_str()));
+    let mut issues = Vec::new();
+
+    for entry in entries {
+        if let Some(id) = &entry.id {
+            // Items with the same name are reported below; a duplicate id is
+            // only worth reporting when the names differ.
+            match ids.get(id.as_str()) {
+                Some(first) if *first != entry.name => issues.push(entry.issue(
+                    IssueKind::DuplicateId,
+                    format!("has the same id as `{first}`, so Yew would mix up their elements"),
+                )),
+                Some(_) => {}
+                None => {
+                    ids.insert(id, &entry.name);
+                }
+            }
+        }
+
+        if !names.insert(&entry.name) {
+            issues.push(entry.issue(
+                IssueKind::DuplicateName,
+                "is listed more than once, so only the first can be looked up by name".to_string(),
+            ));
+        }
+
+        // As above, only the first item with a different name is reported.
+        let first = |index: Option<usize>| {
+            index
+                .map(|index| &entries[index].name)
+                .filter(|first| **first != entry.name)
+        };
+        if let Some(first) = first(lookup.name_ignoring_case(&entry.name)) {
+            issues.push(entry.issue(
+                IssueKind::DuplicateSlug,
+                format!(
+                    "differs from `{first}` only in case, so only the first can be looked up \
+                     ignoring case"
+                ),
+            ));
+        } else if let Some(first) = first(lookup.slug(&slug(&entry.name))) {
+            issues.push(entry.issue(
+                IssueKind::DuplicateSlug,
+                format!(
+                    "has the same slug as `{first}`, `{}`, so only the first can be looked up by \
+                     slug",
+                    slug(&entry.name)
+                ),
+            ));
+        }
+    }
+
+    issues
+}
+
+fn duplicate_keys(entry: &Entry) -> Vec<Issue> {
+    let mut keys: HashSet<(&str, &str)> = HashSet::new();
+
+    entry
+        .keys
+        .iter()
+        .filter(|(field, key)| !keys.insert((field, key.as_str())))
+        .map(|(field, key)| {
+            entry.issue(
+                IssueKind::DuplicateKey,
+                format!("lists the {field} `{key}` more than once"),
+            )
+        })
+        .collect()
+}
+
+fn invalid_dates(entry: &Entry) -> Vec<Issue> {
+    entry
+        .dates
+        .iter()
+        .filter(|(_, date)| !date.is_valid())
+        .map(|(field, date)| {
+            entry.issue(
+                IssueKind::InvalidDate,
+                format!(
+                    "{field} date {} is invalid; did you mean {}?",
+                    date.to_iso_string(),
+                    date.make_valid().to_iso_string()
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Returns the references to items that aren't in the portfolio, apart from
+/// the "not found" values that [`Portfolio::load_unchecked`] replaces broken
+/// references with, which it reports itself.
+fn missing_references(entry: &Entry, portfolio: &Portfolio) -> Vec<Issue> {
+    let areas = entry
+        .areas
+        .iter()
+        .filter(|area| **area != Area::not_found() && portfolio.areas.get(area.0).is_none())
+        .map(|area| format!("references the area `{area}`, which isn't in the area list"));
+    let tools = entry
+        .tools
+        .iter()
+        .filter(|tool| **tool != Tool::not_found() && portfolio.tools.get(tool.0).is_none())
+        .map(|tool| format!("references the tool `{tool}`, which isn't in the tool list"));
+    let skills = entry
+        .skills
+        .iter()
+        .filter(|skill| {
+            skill.name != Skill::not_found().name && portfolio.skills.get(&skill.name).is_none()
+        })
+        .map(|skill| {
+            format!(
+                "references the skill `{}`, which isn't in the skill list",
+                skill.name
+            )
+        });
+
+    areas
+        .chain(tools)
+        .chain(skills)
+        .map(|message| entry.issue(IssueKind::MissingReference, message))
+        .collect()
+}
+
+fn bad_text(entry: &Entry) -> Vec<Issue> {
+    entry
+        .texts
+        .iter()
+        .filter_map(|(field, text)| {
+            if text.trim().is_empty() {
+                Some(entry.issue(IssueKind::EmptyText, format!("{field} is empty")))
+            } else if has_placeholder(text) {
+                Some(entry.issue(
+                    IssueKind::Placeholder,
+                    format!("{field} has placeholder text: {text:?}"),
+                ))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Returns `true` if the text has a placeholder in it, like `<NAME>`: capital
+/// letters, digits, spaces and underscores between angle brackets.
+fn has_placeholder(text: &str) -> bool {
+    text.split('<').skip(1).any(|after| {
+        after.split_once('>').is_some_and(|(inside, _)| {
+            inside.chars().any(|c| c.is_ascii_uppercase())
+                && inside
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == ' ' || c == '_')
+        })
+    })
+}
@@ -0,0 +1,509 @@
+use crate::{Locale, ToHtml};
+use serde_json::json;
+use yew::prelude::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::contact_details;
+    use rstest::rstest;
+
+    #[rstest]
+    fn every_contact_link_is_valid() {
+        for link in &contact_details().links {
+            assert_eq!(link.validate(), Ok(link.url()));
+        }
+    }
+
+    #[rstest]
+    #[case(GITHUB, "kira", "https://github.com/kira/")]
+    #[case(MASTODON, "kira@mastodon.social", "https://mastodon.social/@kira")]
+    #[case(EMAIL, "kira@example.com", "mailto:kira@example.com")]
+    fn contact_link_url_fills_in_template(
+        #[case] platform: Platform,
+        #[case] handle: &'static str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(ContactLink { platform, handle }.url(), expected);
+    }
+
+    #[rstest]
+    #[case(GITHUB, "")]
+    #[case(GITHUB, "kira hudson")]
+    #[case(MASTODON, "kira")]
+    #[case(EMAIL, "kira")]
+    fn contact_link_validate_rejects_bad_handles(
+        #[case] platform: Platform,
+        #[case] handle: &'static str,
+    ) {
+        assert!(ContactLink { platform, handle }.validate().is_err());
+    }
+
+    #[rstest]
+    #[case("Kira", "Kira")]
+    #[case("Hudson, Kira; Pixie", "Hudson\\, Kira\\; Pixie")]
+    #[case("back\\slash", "back\\\\slash")]
+    #[case("two\nlines", "two\\nlines")]
+    fn escape_vcard_works_as_expected(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(escape_vcard(value), expected);
+    }
+
+    #[rstest]
+    #[case("FN:Kira Hudson", "FN:Kira Hudson")]
+    #[case(&"a".repeat(75), &"a".repeat(75))]
+    #[case(&"a".repeat(76), &format!("{}\r\n a", "a".repeat(75)))]
+    #[case(
+        &"a".repeat(150),
+        &format!("{}\r\n {}\r\n {}", "a".repeat(75), "a".repeat(74), "a")
+    )]
+    // A character is never split between lines.
+    #[case(&format!("{}é", "a".repeat(74)), &format!("{}\r\n é", "a".repeat(74)))]
+    fn fold_vcard_line_works_as_expected(#[case] line: &str, #[case] expected: &str) {
+        assert_eq!(fold_vcard_line(line), expected);
+    }
+
+    #[rstest]
+    fn to_vcard_works_as_expected() {
+        let details = ContactDetails {
+            given_name: "Kira",
+            family_name: "Hudson",
+            url: "https://example.com/",
+            links: vec![
+                ContactLink {
+                    platform: EMAIL,
+                    handle: "kira@example.com",
+                },
+                ContactLink {
+                    platform: GITHUB,
+                    handle: "kira",
+                },
+            ],
+        };
+
+        assert_eq!(
+            details.to_vcard(),
+            "BEGIN:VCARD\r\n\
+             VERSION:4.0\r\n\
+             FN:Kira Hudson\r\n\
+             N:Hudson;Kira;;;\r\n\
+             EMAIL:kira@example.com\r\n\
+             URL:https://example.com/\r\n\
+             X-SOCIALPROFILE;TYPE=github:https://github.com/kira/\r\n\
+             END:VCARD\r\n"
+        );
+    }
+
+    #[rstest]
+    fn to_vcard_folds_long_lines() {
+        let details = ContactDetails {
+            given_name: "Kira",
+            family_name: "Hudson",
+            url: "https://example.com/a/very/long/address/that/does/not/fit/on/one/vcard/line/",
+            links: vec![],
+        };
+
+        for line in details.to_vcard().split("\r\n") {
+            assert!(line.len() <= 75, "{line:?} is longer than 75 octets");
+        }
+        assert!(details.to_vcard().contains("\r\n line/\r\n"));
+    }
+}
+
+// ******************************* Platforms  ******************************* //
+// ************************************************************************** //
+
+/// A platform a [`ContactLink`] can point to.
+///
+/// Adding a platform only needs a new constant here; the contact page, footer,
+/// vCard and structured data all render from the [`ContactLink`]s.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Platform {
+    pub name: &'static str,
+    /// The link's address, with placeholders filled in from the handle:
+    ///
+    /// - `{handle}` - the whole handle
+    /// - `{user}` - the part of a `user@instance` handle before the `@`
+    /// - `{instance}` - the part of a `user@instance` handle after the `@`
+    pub url_template: &'static str,
+    /// The Font Awesome classes of the platform's icon.
+    pub icon: &'static str,
+    /// The CSS colours shown on hover. More than one colour is shown as a
+    /// gradient.
+    pub colours: &'static [&'static str],
+}
+
+pub const EMAIL: Platform = Platform {
+    name: "Email",
+    url_template: "mailto:{handle}",
+    icon: "fa-solid fa-square-envelope",
+    colours: &["#282828"],
+};
+
+pub const GITHUB: Platform = Platform {
+    name: "GitHub",
+    url_template: "https://github.com/{handle}/",
+    icon: "fa-brands fa-square-github",
+    colours: &["#282828"],
+};
+
+pub const TUMBLR: Platform = Platform {
+    name: "Tumblr",
+    url_template: "https://www.tumblr.com/blog/{handle}/",
+    icon: "fa-brands fa-square-tumblr",
+    colours: &["#001935"],
+};
+
+pub const INSTAGRAM: Platform = Platform {
+    name: "Instagram",
+    url_template: "https://www.instagram.com/{handle}/",
+    icon: "fa-brands fa-square-instagram",
+    colours: &["#ffbb00", "#ff00d0", "#8800ff"],
+};
+
+pub const PINTEREST: Platform = Platform {
+    name: "Pinterest",
+    url_template: "https://www.pinterest.co.uk/{handle}/",
+    icon: "fa-brands fa-square-pinterest",
+    colours: &["#e60023"],
+};
+
+pub const REDDIT: Platform = Platform {
+    name: "Reddit",
+    url_template: "https://www.reddit.com/user/{handle}/",
+    icon: "fa-brands fa-square-reddit",
+    colours: &["#ff4500"],
+};
+
+pub const MASTODON: Platform = Platform {
+    name: "Mastodon",
+    url_template: "https://{instance}/@{user}",
+    icon: "fa-brands fa-mastodon",
+    colours: &["#6364ff"],
+};
+
+pub const BLUESKY: Platform = Platform {
+    name: "Bluesky",
+    url_template: "https://bsky.app/profile/{handle}",
+    icon: "fa-brands fa-bluesky",
+    colours: &["#1185fe"],
+};
+
+pub const ITCH_IO: Platform = Platform {
+    name: "itch.io",
+    url_template: "https://{handle}.itch.io/",
+    icon: "fa-brands fa-itch-io",
+    colours: &["#fa5c5c"],
+};
+
+pub const LINKEDIN: Platform = Platform {
+    name: "LinkedIn",
+    url_template: "https://www.linkedin.com/in/{handle}/",
+    icon: "fa-brands fa-linkedin",
+    colours: &["#0a66c2"],
+};
+
+impl Platform {
+    /// Returns the inline style setting the platform's hover colours, read by
+    /// `.contact-icon` in `style.css`.
+    pub fn style(&self) -> String {
+        match self.colours {
+            [] => String::new(),
+            [colour] => format!("--brand-colour: {colour};"),
+            colours => format!(
+                "--brand-colour: {}; --brand-gradient: linear-gradient(to bottom right, {});",
+                colours[0],
+                colours.join(", ")
+            ),
+        }
+    }
+}
+
+// ***************************** Contact Links  ***************************** //
+// ************************************************************************** //
+
+/// A handle on a [`Platform`], like an email address or a username.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ContactLink {
+    pub platform: Platform,
+    pub handle: &'static str,
+}
+
+impl ContactLink {
+    /// Returns the link's address, filled in from the platform's template.
+    pub fn url(&self) -> String {
+        let (username, host) = self.handle.split_once('@').unwrap_or((self.handle, ""));
+
+        self.platform
+            .url_template
+            .replace("{handle}", self.handle)
+            .replace("{user}", username)
+            .replace("{instance}", host)
+    }
+
+    /// Returns the link's address if it is valid, or a message describing the
+    /// problem otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the handle is empty or contains whitespace, if the
+    /// template needs a `user@instance` handle and doesn't get one, or if the
+    /// address isn't an `https://` address with a host or a `mailto:` address.
+    pub fn validate(&self) -> Result<String, String> {
+        let name = self.platform.name;
+
+        if self.handle.is_empty() {
+            return Err(format!("{name} handle is empty"));
+        }
+        if self.handle.contains(char::is_whitespace) {
+            return Err(format!(
+                "{name} handle `{}` contains whitespace",
+                self.handle
+            ));
+        }
+
+        let url = self.url();
+
+        if is_valid_url(&url) {
+            Ok(url)
+        } else {
+            Err(format!(
+                "{name} handle `{}` makes an invalid URL `{url}`",
+                self.handle
+            ))
+        }
+    }
+
+    /// Returns the text shown when hovering over the link, like `@handle`.
+    pub fn title(&self) -> String {
+        if self.platform == EMAIL {
+            self.handle.to_string()
+        } else {
+            format!("@{}", self.handle)
+        }
+    }
+
+    /// Returns the link as an icon, with `rel="me"` for identity verification.
+    ///
+    /// Invalid links are left out.
+    pub fn to_icon_html(&self, class: &'static str) -> Html {
+        let Ok(url) = self.validate() else {
+            return html! {};
+        };
+
+        html! {
+            <a
+                class={classes!(
+                    class,
+                    if self.platform == EMAIL { "u-email" } else { "u-url" },
+                    (self.platform.colours.len() > 1).then_some("brand-gradient"),
+                )}
+                href={url}
+                title={self.title()}
+                aria-label={self.title()}
+                style={self.platform.style()}
+                rel={"me"}
+                target={"_blank"}
+            >
+                <i class={self.platform.icon} aria-hidden={"true"}></i>
+            </a>
+        }
+    }
+}
+
+// **************************** Contact Details  **************************** //
+// ************************************************************************** //
+
+/// The site owner's contact details, shared by the contact page, the footer,
+/// the vCard, structured data and the JSON Resume export.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ContactDetails {
+    pub given_name: &'static str,
+    pub family_name: &'static str,
+    /// The address the site is deployed to.
+    pub url: &'static str,
+    /// Every way of contacting the owner, in the order they are shown.
+    pub links: Vec<ContactLink>,
+}
+
+impl ContactDetails {
+    /// Returns the given and family names, separated by a space.
+    pub fn full_name(&self) -> String {
+        format!("{} {}", self.given_name, self.family_name)
+    }
+
+    /// Returns the first email address in the links, if there is one.
+    pub fn email(&self) -> Option<&'static str> {
+        self.links
+            .iter()
+            .find(|link| link.platform == EMAIL)
+            .map(|link| link.handle)
+    }
+
+    /// Returns an iterator over the valid links that aren't email addresses.
+    pub fn social_links(&self) -> impl Iterator<Item = &ContactLink> {
+        self.links
+            .iter()
+            .filter(|link| link.platform != EMAIL && link.validate().is_ok())
+    }
+
+    /// Returns the contact details as a vCard 4.0 (RFC 6350) document.
+    pub fn to_vcard(&self) -> String {
+        let mut lines = vec![
+            String::from("BEGIN:VCARD"),
+            String::from("VERSION:4.0"),
+            format!("FN:{}", escape_vcard(&self.full_name())),
+            format!(
+                "N:{};{};;;",
+                escape_vcard(self.family_name),
+                escape_vcard(self.given_name)
+            ),
+        ];
+
+        lines.extend(self.email().map(|email| format!("EMAIL:{email}")));
+        lines.push(format!("URL:{}", self.url));
+        lines.extend(self.social_links().map(|link| {
+            format!(
+                "X-SOCIALPROFILE;TYPE={}:{}",
+                link.platform.name.to_lowercase(),
+                link.url()
+            )
+        }));
+        lines.push(String::from("END:VCARD"));
+
+        lines
+            .iter()
+            .map(|line| fold_vcard_line(line))
+            .collect::<Vec<String>>()
+            .join("\r\n")
+            + "\r\n"
+    }
+
+    /// Returns the vCard as a `data:` URI, for use as a download link.
+    pub fn vcard_data_uri(&self) -> String {
+        format!(
+            "data:text/vcard;charset=utf-8,{}",
+            percent_encode(&self.to_vcard())
+        )
+    }
+
+    /// Returns the file name the vCard is downloaded as, like
+    /// `kira-hudson.vcf`.
+    pub fn vcard_file_name(&self) -> String {
+        format!(
+            "{}-{}.vcf",
+            self.given_name.to_lowercase(),
+            self.family_name.to_lowercase()
+        )
+    }
+
+    /// Returns a schema.org `Person` as JSON-LD structured data.
+    pub fn to_json_ld(&self) -> serde_json::Value {
+        json!({
+            "@context": "https://schema.org",
+            "@type": "Person",
+            "name": self.full_name(),
+            "givenName": self.given_name,
+            "familyName": self.family_name,
+            "url": self.url,
+            "email": self.email().map(|email| format!("mailto:{email}")),
+            "sameAs": self.social_links().map(ContactLink::url).collect::<Vec<String>>(),
+        })
+    }
+
+    /// Returns every link as an icon, with the given class.
+    pub fn icons_to_html(&self, class: &'static str) -> Html {
+        self.links
+            .iter()
+            .map(|link| link.to_icon_html(class))
+            .collect::<Html>()
+    }
+}
+
+/// Returns `true` if `url` is a `mailto:` address, or an `https://` address
+/// with a host.
+fn is_valid_url(url: &str) -> bool {
+    if let Some(address) = url.strip_prefix("mailto:") {
+        return address
+            .split_once('@')
+            .is_some_and(|(user, host)| !user.is_empty() && host.contains('.'));
+    }
+
+    url.strip_prefix("https://")
+        .and_then(|rest| rest.split('/').next())
+        .is_some_and(|host| host.contains('.') && !host.starts_with('.'))
+}
+
+/// Escapes the characters with special meaning in vCard text values.
+fn escape_vcard(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace(';', "\\;")
+        .replace('\n', "\\n")
+}
+
+/// Folds a vCard content line into lines of at most 75 octets, each after the
+/// first starting with a space, as RFC 6350 §3.2 requires.
+fn fold_vcard_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut length = 0;
+
+    for character in line.chars() {
+        if length + character.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(character);
+        length += character.len_utf8();
+    }
+
+    folded
+}
+
+/// Percent-encodes every byte of `value` other than the URI unreserved
+/// characters, for use in `data:` URIs and query strings.
+pub(crate) fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                char::from(byte).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+impl ToHtml for ContactDetails {
+    /// Returns the contact details as a microformats2 `h-card`.
+    fn to_html(&self) -> Html {
+        html! {
+            <div class={"h-card"}>
+                <p>
+                    <img class={"u-photo contact-photo"} src={"/icon.png"} alt={""} />
+                    <a class={"p-name u-url u-uid"} href={self.url} rel={"me"}>
+                        <span class={"p-given-name"}>{self.given_name}</span>
+                        {" "}
+                        <span class={"p-family-name"}>{self.family_name}</span>
+                    </a>
+                </p>
+
+                <div class={"contact-details-container"}>
+                    {self.icons_to_html("contact-icon")}
+                </div>
+
+                <p>
+                    <a href={self.vcard_data_uri()} download={self.vcard_file_name()}>
+                        <i class={"fa-solid fa-address-card"} aria-hidden={"true"}></i>
+                        {Locale::current().messages().download_contact_card}
+                    </a>
+                </p>
+            </div>
+        }
+    }
+}
+
+#[derive(Properties, PartialEq, Eq)]
+pub struct ContactProperties {
+    pub details: ContactDetails,
+}
@@ -0,0 +1,546 @@
+use crate::{contact::percent_encode, Locale};
+use serde::Serialize;
+use std::{fmt::Display, future::Future, pin::Pin, rc::Rc};
+use web_sys::{HtmlInputElement, HtmlTextAreaElement};
+use yew::{platform::spawn_local, prelude::*};
+
+#[cfg(test)]
+#[allow(clippy::future_not_send)] // Submissions run in the single-threaded browser.
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use wiremock::{
+        matchers::{body_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    fn message() -> ContactMessage {
+        ContactMessage {
+            name: String::from("Kira"),
+            email: String::from("kira@example.com"),
+            message: String::from("Hello there, this is a message."),
+            honeypot: String::new(),
+        }
+    }
+
+    #[rstest]
+    #[case("kira@example.com", true)]
+    #[case("kira.hudson+site@mail.example.co.uk", true)]
+    #[case("kira", false)]
+    #[case("kira@", false)]
+    #[case("@example.com", false)]
+    #[case("kira@example", false)]
+    #[case("kira hudson@example.com", false)]
+    fn is_valid_email_works_as_expected(#[case] email: &str, #[case] expected: bool) {
+        assert_eq!(is_valid_email(email), expected);
+    }
+
+    #[rstest]
+    fn validate_reports_every_invalid_field() {
+        let message = ContactMessage {
+            name: String::new(),
+            email: String::from("kira"),
+            message: String::from("Hi"),
+            honeypot: String::new(),
+        };
+
+        assert_eq!(
+            message.validate(),
+            Err(vec![
+                FieldError::NameLength,
+                FieldError::Email,
+                FieldError::MessageLength,
+            ])
+        );
+        assert_eq!(self::message().validate(), Ok(()));
+    }
+
+    #[rstest]
+    fn mailto_backend_encodes_message() {
+        let backend = MailtoBackend::new("kira@example.com");
+
+        assert_eq!(
+            backend.mailto_url(&message()),
+            "mailto:kira@example.com?subject=Message%20from%20Kira\
+             &body=Hello%20there%2C%20this%20is%20a%20message.%0D%0A%0D%0A\
+             Kira%20%3Ckira%40example.com%3E"
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn http_backend_posts_message_as_json() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/contact"))
+            .and(body_json(serde_json::json!({
+                "name": "Kira",
+                "email": "kira@example.com",
+                "message": "Hello there, this is a message.",
+            })))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let backend = HttpBackend::new(format!("{}/contact", server.uri()));
+
+        assert_eq!(backend.submit(message()).await, Ok(Delivery::Sent));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn http_backend_reports_error_status() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        let backend = HttpBackend::new(server.uri());
+
+        assert_eq!(
+            backend.submit(message()).await,
+            Err(ContactError::Status(503))
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn http_backend_drops_spam_without_sending() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let backend = HttpBackend::new(server.uri());
+        let spam = ContactMessage {
+            honeypot: String::from("https://spam.example.com"),
+            ..message()
+        };
+
+        assert_eq!(backend.submit(spam).await, Ok(Delivery::Sent));
+    }
+}
+
+// ******************************** Messages ******************************** //
+// ************************************************************************** //
+
+/// The longest name accepted, in characters.
+pub const MAX_NAME_LENGTH: usize = 100;
+
+/// The shortest message accepted, in characters.
+pub const MIN_MESSAGE_LENGTH: usize = 10;
+
+/// The longest message accepted, in characters.
+pub const MAX_MESSAGE_LENGTH: usize = 5000;
+
+/// A message sent through the contact form.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ContactMessage {
+    pub name: String,
+    pub email: String,
+    pub message: String,
+    /// A field hidden from people, which only spam bots fill in.
+    #[serde(skip)]
+    pub honeypot: String,
+}
+
+/// A problem with one of a [`ContactMessage`]'s fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldError {
+    NameLength,
+    Email,
+    MessageLength,
+}
+
+impl Display for FieldError {
+    /// Writes the message shown for the field in the current locale.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Locale::current().messages().field_error(*self))
+    }
+}
+
+impl ContactMessage {
+    /// Returns `true` if the hidden honeypot field was filled in.
+    pub const fn is_spam(&self) -> bool {
+        !self.honeypot.is_empty()
+    }
+
+    /// Checks every field, returning all of the problems found.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`FieldError`]s of every invalid field, in form order.
+    pub fn validate(&self) -> Result<(), Vec<FieldError>> {
+        let name_length = self.name.trim().chars().count();
+        let message_length = self.message.trim().chars().count();
+
+        let errors = [
+            (!(1..=MAX_NAME_LENGTH).contains(&name_length)).then_some(FieldError::NameLength),
+            (!is_valid_email(self.email.trim())).then_some(FieldError::Email),
+            (!(MIN_MESSAGE_LENGTH..=MAX_MESSAGE_LENGTH).contains(&message_length))
+                .then_some(FieldError::MessageLength),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<FieldError>>();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Returns `true` if `email` looks like an address: something, an `@`, then a
+/// domain with a dot in it, and no whitespace.
+pub fn is_valid_email(email: &str) -> bool {
+    if email.contains(char::is_whitespace) {
+        return false;
+    }
+
+    email.rsplit_once('@').is_some_and(|(user, domain)| {
+        !user.is_empty()
+            && domain
+                .split_once('.')
+                .is_some_and(|(name, tld)| !name.is_empty() && !tld.is_empty())
+    })
+}
+
+// ******************************** Backends ******************************** //
+// ************************************************************************** //
+
+/// The reasons a [`ContactBackend`] can fail to send a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContactError {
+    /// The request couldn't be made, like when offline.
+    Network(String),
+    /// The endpoint responded with an unsuccessful HTTP status.
+    Status(u16),
+    /// The backend can't be used here, like a `mailto:` link outside a browser.
+    Unavailable,
+}
+
+impl Display for ContactError {
+    /// Writes the message shown for the error in the current locale.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Locale::current().messages().contact_error(self))
+    }
+}
+
+/// What became of a message a [`ContactBackend`] accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    /// The message was delivered.
+    Sent,
+    /// The message was written out in the visitor's email app, which they
+    /// still have to send it from.
+    Drafted,
+}
+
+/// The future returned by [`ContactBackend::submit`].
+pub type Submission = Pin<Box<dyn Future<Output = Result<Delivery, ContactError>>>>;
+
+/// A way of delivering messages from the contact form.
+pub trait ContactBackend {
+    /// Sends the message. Spam should be dropped, but still reported as sent.
+    fn submit(&self, message: ContactMessage) -> Submission;
+
+    /// Returns the text of the form's submit button, in the current locale.
+    fn action(&self) -> &'static str {
+        Locale::current().messages().send
+    }
+}
+
+/// Sends messages as JSON in a `POST` request to an HTTP endpoint, like a form
+/// handling service.
+#[derive(Clone, PartialEq, Eq)]
+pub struct HttpBackend {
+    endpoint: String,
+}
+
+impl HttpBackend {
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+        }
+    }
+}
+
+impl ContactBackend for HttpBackend {
+    fn submit(&self, message: ContactMessage) -> Submission {
+        let endpoint = self.endpoint.clone();
+
+        Box::pin(async move {
+            if message.is_spam() {
+                return Ok(Delivery::Sent);
+            }
+
+            let response = reqwest::Client::new()
+                .post(endpoint)
+                .header("Accept", "application/json")
+                .json(&message)
+                .send()
+                .await
+                .map_err(|error| ContactError::Network(error.to_string()))?;
+
+            if response.status().is_success() {
+                Ok(Delivery::Sent)
+            } else {
+                Err(ContactError::Status(response.status().as_u16()))
+            }
+        })
+    }
+}
+
+/// Opens the visitor's email client with the message filled in, for when no
+/// HTTP endpoint is configured.
+#[derive(Clone, PartialEq, Eq)]
+pub struct MailtoBackend {
+    address: String,
+}
+
+impl MailtoBackend {
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+        }
+    }
+
+    /// Returns the `mailto:` URL with the message as its subject and body.
+    pub fn mailto_url(&self, message: &ContactMessage) -> String {
+        let subject = format!("Message from {}", message.name.trim());
+        let body = format!(
+            "{}\r\n\r\n{} <{}>",
+            message.message.trim(),
+            message.name.trim(),
+            message.email.trim()
+        );
+
+        format!(
+            "mailto:{}?subject={}&body={}",
+            self.address,
+            percent_encode(&subject),
+            percent_encode(&body)
+        )
+    }
+}
+
+impl ContactBackend for MailtoBackend {
+    fn submit(&self, message: ContactMessage) -> Submission {
+        let url = self.mailto_url(&message);
+
+        Box::pin(async move {
+            if message.is_spam() {
+                return Ok(Delivery::Drafted);
+            }
+
+            web_sys::window()
+                .ok_or(ContactError::Unavailable)?
+                .location()
+                .set_href(&url)
+                .map(|()| Delivery::Drafted)
+                .map_err(|_| ContactError::Unavailable)
+        })
+    }
+
+    fn action(&self) -> &'static str {
+        Locale::current().messages().open_in_email_app
+    }
+}
+
+// ******************************* Component  ******************************* //
+// ************************************************************************** //
+
+/// The state of the contact form.
+#[derive(Clone, PartialEq, Eq)]
+enum FormStatus {
+    Editing,
+    Sending,
+    Sent,
+    /// The message is waiting in the visitor's email app.
+    Drafted,
+    Failed(String),
+}
+
+#[derive(Properties)]
+pub struct ContactFormProperties {
+    pub backend: Rc<dyn ContactBackend>,
+}
+
+impl PartialEq for ContactFormProperties {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.backend, &other.backend)
+    }
+}
+
+/// A contact form, validated in the browser before being handed to a
+/// [`ContactBackend`].
+#[function_component(ContactForm)]
+pub fn contact_form(ContactFormProperties { backend }: &ContactFormProperties) -> Html {
+    let message = use_state(ContactMessage::default);
+    let errors = use_state(Vec::<FieldError>::new);
+    let status = use_state(|| FormStatus::Editing);
+
+    let on_name = {
+        let message = message.clone();
+        Callback::from(move |e: InputEvent| {
+            let name = e.target_unchecked_into::<HtmlInputElement>().value();
+            message.set(ContactMessage {
+                name,
+                ..(*message).clone()
+            });
+        })
+    };
+    let on_email = {
+        let message = message.clone();
+        Callback::from(move |e: InputEvent| {
+            let email = e.target_unchecked_into::<HtmlInputElement>().value();
+            message.set(ContactMessage {
+                email,
+                ..(*message).clone()
+            });
+        })
+    };
+    let on_message = {
+        let message = message.clone();
+        Callback::from(move |e: InputEvent| {
+            let text = e.target_unchecked_into::<HtmlTextAreaElement>().value();
+            message.set(ContactMessage {
+                message: text,
+                ..(*message).clone()
+            });
+        })
+    };
+    let on_honeypot = {
+        let message = message.clone();
+        Callback::from(move |e: InputEvent| {
+            let honeypot = e.target_unchecked_into::<HtmlInputElement>().value();
+            message.set(ContactMessage {
+                honeypot,
+                ..(*message).clone()
+            });
+        })
+    };
+
+    let on_submit = {
+        let backend = backend.clone();
+        let message = message.clone();
+        let errors = errors.clone();
+        let status = status.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            if let Err(found) = message.validate() {
+                errors.set(found);
+                return;
+            }
+            errors.set(Vec::new());
+            status.set(FormStatus::Sending);
+
+            let submission = backend.submit((*message).clone());
+            let status = status.clone();
+            spawn_local(async move {
+                match submission.await {
+                    Ok(Delivery::Sent) => status.set(FormStatus::Sent),
+                    Ok(Delivery::Drafted) => status.set(FormStatus::Drafted),
+                    Err(error) => status.set(FormStatus::Failed(error.to_string())),
+                }
+            });
+        })
+    };
+
+    let error_for = |field: FieldError| {
+        errors.contains(&field).then(|| {
+            html! {
+                <p class={"form-error"} role={"alert"}>{field.to_string()}</p>
+            }
+        })
+    };
+
+    let messages = Locale::current().messages();
+
+    let outcome = match *status {
+        FormStatus::Sent => Some(messages.message_sent),
+        FormStatus::Drafted => Some(messages.message_drafted),
+        _ => None,
+    };
+    if let Some(outcome) = outcome {
+        return html! {
+            <div class={"contact-form form-success"} role={"status"}>
+                <p>{outcome}</p>
+            </div>
+        };
+    }
+
+    html! {
+        <form class={"contact-form"} onsubmit={on_submit} novalidate={true}>
+            <label for={"contact-name"}>{messages.name}</label>
+            <input
+                id={"contact-name"}
+                type={"text"}
+                autocomplete={"name"}
+                maxlength={MAX_NAME_LENGTH.to_string()}
+                required={true}
+                value={message.name.clone()}
+                oninput={on_name}
+            />
+            {error_for(FieldError::NameLength)}
+
+            <label for={"contact-email"}>{messages.email}</label>
+            <input
+                id={"contact-email"}
+                type={"email"}
+                autocomplete={"email"}
+                required={true}
+                value={message.email.clone()}
+                oninput={on_email}
+            />
+            {error_for(FieldError::Email)}
+
+            <label for={"contact-message"}>{messages.message}</label>
+            <textarea
+                id={"contact-message"}
+                rows={"6"}
+                minlength={MIN_MESSAGE_LENGTH.to_string()}
+                maxlength={MAX_MESSAGE_LENGTH.to_string()}
+                required={true}
+                value={message.message.clone()}
+                oninput={on_message}
+            />
+            <p class={"form-hint"}>
+                {format!("{} / {MAX_MESSAGE_LENGTH}", message.message.chars().count())}
+            </p>
+            {error_for(FieldError::MessageLength)}
+
+            <div class={"form-honeypot"} aria-hidden={"true"}>
+                <label for={"contact-website"}>{messages.leave_empty}</label>
+                <input
+                    id={"contact-website"}
+                    type={"text"}
+                    tabindex={"-1"}
+                    autocomplete={"off"}
+                    value={message.honeypot.clone()}
+                    oninput={on_honeypot}
+                />
+            </div>
+
+            if let FormStatus::Failed(error) = &*status {
+                <p class={"form-error"} role={"alert"}>{error.clone()}</p>
+            }
+
+            <button
+                class={"list-control"}
+                type={"submit"}
+                disabled={*status == FormStatus::Sending}
+            >
+                {if *status == FormStatus::Sending { messages.sending } else { backend.action() }}
+            </button>
+        </form>
+    }
+}
@@ -1,6 +1,442 @@
-use crate::{KiraDate, NotFound};
-use std::{fmt::Display, ops::Index, rc::Rc};
+use crate::{
+    slug, thumbnail, DateStyle, FormattedDate, Gallery, Id, KiraDate, Locale, Localised, Lookup,
+    Media, NotFound, Route, Year,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    ops::Index,
+    rc::Rc,
+};
 use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use yew::ServerRenderer;
+
+    fn creation(name: &str, completed: KiraDate) -> Creation {
+        Creation {
+            name: name.to_string(),
+            completed,
+            areas: vec![],
+            tools: vec![],
+            skills: vec![],
+            description: Localised::from("A creation."),
+            media: vec![],
+        }
+    }
+
+    #[rstest]
+    fn get_try_get_works_as_expected() {
+        let areas = AreaList::from(vec![Area("development"), Area("scripting")]);
+
+        assert_eq!(
+            areas.try_get("scripting", "skills", "Python"),
+            Ok(Area("scripting"))
+        );
+        assert_eq!(
+            areas.try_get("graphics", "skills", "Python"),
+            Err(ContentError::Missing {
+                list: "areas",
+                name: "graphics".to_string(),
+                from_list: "skills",
+                from: "Python".to_string(),
+            })
+        );
+    }
+
+    #[rstest]
+    fn indexed_gets_work_as_expected() {
+        let date = KiraDate::new(2023, 9, 16);
+        let creations =
+            CreationList::new(vec![creation("Web Profile", date), creation("C++", date)]);
+        let name = |creation: &Creation| creation.name.clone();
+
+        assert_eq!(
+            creations.get("Web Profile").as_ref().map(name),
+            Some("Web Profile".to_string())
+        );
+        assert_eq!(creations.get("web profile").as_ref().map(name), None);
+        assert_eq!(
+            creations.get_ignoring_case("WEB PROFILE").map(name),
+            Some("Web Profile".to_string())
+        );
+        assert_eq!(
+            creations.get_by_slug("web-profile").map(name),
+            Some("Web Profile".to_string())
+        );
+        assert_eq!(
+            creations.get_by_slug("c").map(name),
+            Some("C++".to_string())
+        );
+        assert_eq!(
+            creations.get_by_id(Id::from_name("C++")).map(name),
+            Some("C++".to_string())
+        );
+        assert_eq!(creations.get_by_id(Id::from_name("C#")).map(name), None);
+    }
+
+    fn names<T: ContentItem>(items: &[&T]) -> Vec<String> {
+        items.iter().map(|item| item.name().to_string()).collect()
+    }
+
+    fn creations() -> CreationList {
+        let web = Rc::new(Area("development/web"));
+
+        CreationList::new(vec![
+            creation("Web Profile", KiraDate::new(2023, 9, 16)),
+            Creation {
+                areas: vec![web.clone()],
+                tools: vec![Rc::new(Tool("Visual Studio Code"))],
+                ..creation("blog", KiraDate::new(2024, 2, 1))
+            },
+            Creation {
+                areas: vec![web],
+                ..creation("Archive", KiraDate::new(2022, 6, 30))
+            },
+        ])
+    }
+
+    #[rstest]
+    #[case(ContentOrder::Declared, ["Web Profile", "blog", "Archive"])]
+    #[case(ContentOrder::Newest, ["blog", "Web Profile", "Archive"])]
+    #[case(ContentOrder::Oldest, ["Archive", "Web Profile", "blog"])]
+    #[case(ContentOrder::Name, ["Archive", "blog", "Web Profile"])]
+    fn content_list_sorted_works_as_expected(
+        #[case] order: ContentOrder,
+        #[case] expected: [&str; 3],
+    ) {
+        assert_eq!(names(&creations().sorted(order)), expected);
+    }
+
+    #[rstest]
+    fn content_list_filters_work_as_expected() {
+        let creations = creations();
+
+        assert_eq!(
+            names(&creations.with_area(Area("development/web"))),
+            ["blog", "Archive"]
+        );
+        assert!(creations.with_area(Area("development")).is_empty());
+        assert_eq!(
+            names(&creations.within_area(Area("development"))),
+            ["blog", "Archive"]
+        );
+        assert_eq!(
+            names(&creations.with_tool(Tool("Visual Studio Code"))),
+            ["blog"]
+        );
+        assert!(creations.with_skill("Rust").is_empty());
+        assert_eq!(names(&creations.search("BLOG")), ["blog"]);
+        assert_eq!(
+            names(&creations.search("web")),
+            ["Web Profile", "blog", "Archive"]
+        );
+        assert_eq!(names(&creations.search("studio")), ["blog"]);
+        assert_eq!(creations.search("").len(), 3);
+    }
+
+    #[rstest]
+    #[case(0, 2, &["Web Profile", "blog"])]
+    #[case(1, 2, &["Archive"])]
+    #[case(2, 2, &[])]
+    #[case(0, 5, &["Web Profile", "blog", "Archive"])]
+    fn content_list_page_works_as_expected(
+        #[case] number: usize,
+        #[case] size: usize,
+        #[case] expected: &[&str],
+    ) {
+        let creations = creations();
+        let page = creations
+            .page(number, size)
+            .iter()
+            .collect::<Vec<&Creation>>();
+
+        assert_eq!(names(&page), expected);
+        assert_eq!(creations.page_count(size), 3_usize.div_ceil(size));
+    }
+
+    fn project(releases: &[(&str, KiraDate)]) -> Project {
+        Project {
+            name: "Project".to_string(),
+            started: KiraDate::new(2023, 1, 1),
+            status: ProjectStatus::Active,
+            repository: None,
+            demo: None,
+            licence: None,
+            languages: vec![],
+            media: vec![],
+            releases: releases
+                .iter()
+                .map(|(version, date)| Release {
+                    version: (*version).to_string(),
+                    date: *date,
+                    notes: Localised::default(),
+                })
+                .collect(),
+            areas: vec![],
+            tools: vec![],
+            skills: vec![],
+            description: Localised::from("A project."),
+        }
+    }
+
+    #[rstest]
+    #[case(&[], None)]
+    #[case(&[("0.1.0", KiraDate::new(2023, 2, 1))], Some("0.1.0"))]
+    #[case(
+        &[("1.0.0", KiraDate::new(2024, 1, 1)), ("0.9.0", KiraDate::new(2023, 6, 1))],
+        Some("1.0.0")
+    )]
+    fn project_latest_release_works_as_expected(
+        #[case] releases: &[(&str, KiraDate)],
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(
+            project(releases)
+                .latest_release()
+                .map(|release| release.version.as_str()),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn project_dates_include_releases() {
+        let project = project(&[("0.1.0", KiraDate::new(2023, 2, 1))]);
+
+        assert_eq!(
+            project.dates(),
+            vec![
+                ("started", KiraDate::new(2023, 1, 1)),
+                ("release", KiraDate::new(2023, 2, 1)),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn skill_usages_index_education_and_experience() {
+        let skill = Rc::new(Skill::not_found());
+        let education = EducationList::new(vec![Education {
+            qualification: "BSc Computer Science".to_string(),
+            institution: "Example University".to_string(),
+            started: KiraDate::new(2021, 9, 1),
+            finished: None,
+            areas: vec![],
+            skills: vec![Rc::clone(&skill)],
+            description: Localised::from("A degree."),
+        }]);
+        let experience = ExperienceList::new(vec![Experience {
+            role: "Developer".to_string(),
+            organisation: "Example Ltd".to_string(),
+            kind: ExperienceKind::Work,
+            started: KiraDate::new(2023, 6, 1),
+            ended: None,
+            areas: vec![],
+            skills: vec![Rc::clone(&skill)],
+            description: Localised::from("A role."),
+        }]);
+        let projects = ProjectList::new(vec![Project {
+            skills: vec![Rc::clone(&skill)],
+            ..project(&[])
+        }]);
+
+        let usage = SkillUsages::new(
+            &AchievementList::new(vec![]),
+            &CreationList::new(vec![]),
+            &projects,
+            &education,
+            &experience,
+        )
+        .get(&skill);
+
+        assert_eq!(
+            usage,
+            SkillUsage {
+                achievements: vec![],
+                creations: vec![],
+                projects: vec!["Project".to_string()],
+                education: vec!["BSc Computer Science".to_string()],
+                experience: vec!["Developer".to_string()],
+            }
+        );
+    }
+
+    #[rstest]
+    fn skill_experience_needs_a_start_date() {
+        let skill = Skill {
+            started: None,
+            last_used: Some(KiraDate::new(2023, 1, 1)),
+            ..Skill::not_found()
+        };
+
+        assert_eq!(skill.years_of_experience(), None);
+        assert_eq!(skill.experience_to_string(), None);
+        assert_eq!(
+            Skill {
+                started: Some(KiraDate::new(2020, 1, 1)),
+                ..skill
+            }
+            .years_of_experience(),
+            Some(3)
+        );
+    }
+
+    #[derive(Properties, PartialEq)]
+    struct HistoryProperties {
+        history: Vec<CompetencyChange>,
+    }
+
+    #[function_component(History)]
+    fn history(HistoryProperties { history }: &HistoryProperties) -> Html {
+        Skill {
+            history: history.clone(),
+            ..Skill::not_found()
+        }
+        .history_to_html()
+    }
+
+    async fn render_history(history: Vec<CompetencyChange>) -> String {
+        ServerRenderer::<History>::with_props(move || HistoryProperties { history })
+            .hydratable(false)
+            .render()
+            .await
+    }
+
+    #[tokio::test]
+    async fn skill_history_renders_only_when_recorded() {
+        let recorded = render_history(vec![CompetencyChange {
+            date: KiraDate::new(2023, 1, 1),
+            competency: Competency::Novice,
+        }])
+        .await;
+
+        assert!(recorded.contains("competency-chart-row"));
+        assert!(recorded.contains(
+            Locale::current()
+                .messages()
+                .competency_name(Competency::Novice)
+        ));
+        assert!(!render_history(vec![]).await.contains("competency-history"));
+    }
+
+    #[rstest]
+    #[case(Area("development"), vec![])]
+    #[case(Area("development/web"), vec![Area("development")])]
+    #[case(
+        Area("development/web/frontend"),
+        vec![Area("development"), Area("development/web")],
+    )]
+    fn area_ancestors_works_as_expected(#[case] area: Area, #[case] expected: Vec<Area>) {
+        assert_eq!(area.ancestors(), expected);
+    }
+
+    #[rstest]
+    #[case(Area("development"), Area("development"), true)]
+    #[case(Area("development/web"), Area("development"), true)]
+    #[case(Area("development"), Area("development/web"), false)]
+    #[case(Area("developments"), Area("development"), false)]
+    fn area_is_within_works_as_expected(
+        #[case] area: Area,
+        #[case] other: Area,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(area.is_within(other), expected);
+    }
+
+    #[rstest]
+    fn area_list_try_new_rejects_duplicates() {
+        assert_eq!(
+            AreaList::try_new(vec![Area("scripting"), Area("scripting")]).err(),
+            Some(vec![ContentError::Duplicate {
+                list: "areas",
+                name: "scripting".to_string(),
+            }])
+        );
+    }
+
+    #[rstest]
+    fn creation_list_try_new_rejects_invalid_data() {
+        let date = KiraDate::new(2023, 9, 16);
+        let invalid = KiraDate::new(2023, 2, 30);
+
+        assert!(CreationList::try_new(vec![creation("A", date), creation("B", date)]).is_ok());
+        assert_eq!(
+            CreationList::try_new(vec![creation("A", date), creation("A", date)]).err(),
+            Some(vec![ContentError::Duplicate {
+                list: "creations",
+                name: "A".to_string(),
+            }])
+        );
+        assert_eq!(
+            CreationList::try_new(vec![creation("A", invalid)]).err(),
+            Some(vec![ContentError::InvalidDate {
+                list: "creations",
+                name: "A".to_string(),
+                date: invalid,
+            }])
+        );
+    }
+
+    #[rstest]
+    fn creation_list_try_new_reports_every_error() {
+        let date = KiraDate::new(2023, 9, 16);
+        let invalid = KiraDate::new(2023, 2, 30);
+
+        assert_eq!(
+            CreationList::try_new(vec![creation("A", invalid), creation("A", date)]).err(),
+            Some(vec![
+                ContentError::Duplicate {
+                    list: "creations",
+                    name: "A".to_string(),
+                },
+                ContentError::InvalidDate {
+                    list: "creations",
+                    name: "A".to_string(),
+                    date: invalid,
+                },
+            ])
+        );
+    }
+
+    #[rstest]
+    fn get_or_record_records_every_missing_reference() {
+        let areas = AreaList::from(vec![Area("scripting")]);
+        let mut errors = Vec::new();
+
+        assert_eq!(
+            areas.get_or_record("scripting", "skills", "Rust", &mut errors),
+            Area("scripting")
+        );
+        assert_eq!(
+            areas.get_or_record("nowhere", "skills", "Rust", &mut errors),
+            Area::not_found()
+        );
+        assert_eq!(
+            areas.get_or_record("elsewhere", "skills", "Python", &mut errors),
+            Area::not_found()
+        );
+        assert_eq!(
+            errors,
+            vec![
+                ContentError::Missing {
+                    list: "areas",
+                    name: "nowhere".to_string(),
+                    from_list: "skills",
+                    from: "Rust".to_string(),
+                },
+                ContentError::Missing {
+                    list: "areas",
+                    name: "elsewhere".to_string(),
+                    from_list: "skills",
+                    from: "Python".to_string(),
+                },
+            ]
+        );
+    }
+}
 
 // ********************************* Traits ********************************* //
 // ************************************************************************** //
@@ -19,6 +455,9 @@ pub trait NotFoundValue {
 
 /// Trait for finding items in a list.
 pub trait Get<K, V> {
+    /// The list's name, like `"areas"`, for errors.
+    const LIST: &'static str;
+
     /// Finds the first item in the list matching the given identifier (`id`),
     /// or `None` if no match is found.
     ///
@@ -26,10 +465,46 @@ pub trait Get<K, V> {
     fn get(&self, id: K) -> Option<V>;
 
     /// Finds the first item in the list matching the given identifier (`id`),
-    /// or panics with the default unwrap message if no match is found.
-    #[allow(clippy::unwrap_used)]
-    fn get_unwrap(&self, id: K) -> V {
-        self.get(id).unwrap()
+    /// or returns a [`ContentError::Missing`] if no match is found.
+    ///
+    /// `from_list` and `from` name the item making the reference, like
+    /// `"skills"` and `"Rust"`, so the error can say where the broken
+    /// reference is.
+    ///
+    /// # Errors
+    ///
+    /// Fails if no item matches `id`.
+    fn try_get(&self, id: K, from_list: &'static str, from: &str) -> Result<V, ContentError>
+    where
+        K: Display + Copy,
+    {
+        self.get(id).ok_or_else(|| ContentError::Missing {
+            list: Self::LIST,
+            name: id.to_string(),
+            from_list,
+            from: from.to_string(),
+        })
+    }
+
+    /// Finds the first item in the list matching the given identifier (`id`),
+    /// or records a [`ContentError::Missing`] in `errors` and returns the "not
+    /// found" value in its place, so that content can be built in full and
+    /// every broken reference in it reported at once.
+    fn get_or_record(
+        &self,
+        id: K,
+        from_list: &'static str,
+        from: &str,
+        errors: &mut Vec<ContentError>,
+    ) -> V
+    where
+        K: Display + Copy,
+        V: NotFoundValue,
+    {
+        self.try_get(id, from_list, from).unwrap_or_else(|error| {
+            errors.push(error);
+            V::not_found()
+        })
     }
 
     /// Finds the first item in the list matching the given identifier (`id`),
@@ -37,12 +512,243 @@ pub trait Get<K, V> {
     fn get_unwrap_or(&self, id: K, default: V) -> V {
         self.get(id).unwrap_or(default)
     }
+}
+
+/// Trait for lists that index their items when they're built, so that they
+/// can be found without searching the whole list.
+pub trait Indexed<T> {
+    /// Returns the list's index of its items.
+    fn lookup(&self) -> &Lookup<T>;
+
+    /// Returns the items in the list, in the order they were indexed.
+    fn items(&self) -> &[T];
+
+    /// Finds the first item in the list with the given name, or `None` if no
+    /// match is found.
+    fn get_by_name(&self, name: &str) -> Option<&T> {
+        self.lookup()
+            .name(name)
+            .and_then(|index| self.items().get(index))
+    }
 
-    /// Finds the first item in the list mathing the given identifier (`id`),
-    /// or panics with the specified message (`msg`) if no match is found.
-    #[allow(dead_code)]
-    fn get_expect(&self, id: K, msg: &str) -> V {
-        self.get(id).expect(msg)
+    /// Finds the first item in the list with the given name, ignoring case, or
+    /// `None` if no match is found.
+    fn get_ignoring_case(&self, name: &str) -> Option<&T> {
+        self.lookup()
+            .name_ignoring_case(name)
+            .and_then(|index| self.items().get(index))
+    }
+
+    /// Finds the first item in the list with the given [`slug`](crate::slug),
+    /// or `None` if no match is found.
+    fn get_by_slug(&self, slug: &str) -> Option<&T> {
+        self.lookup()
+            .slug(slug)
+            .and_then(|index| self.items().get(index))
+    }
+
+    /// Finds the item in the list with the given id, or `None` if no match is
+    /// found.
+    fn get_by_id(&self, id: Id<T>) -> Option<&T> {
+        self.lookup()
+            .id(id)
+            .and_then(|index| self.items().get(index))
+    }
+}
+
+/// Trait for the types of content that make up the portfolio, like
+/// [`Skill`]s and [`Article`]s, so that a [`ContentList`] of any of them can
+/// be searched, sorted and paged in the same way.
+pub trait ContentItem: Clone {
+    /// The name of a list of the items, like `"skills"`, for errors.
+    const LIST: &'static str;
+
+    /// Returns the item's name, which its page is found by.
+    fn name(&self) -> &str;
+
+    /// Returns the item's id, from its name.
+    fn id(&self) -> Id<Self> {
+        Id::from_name(self.name())
+    }
+
+    /// Returns the item's [`slug`], from its name.
+    fn slug(&self) -> String {
+        slug(self.name())
+    }
+
+    /// Returns the date the item is listed under, like when it was completed.
+    fn date(&self) -> KiraDate;
+
+    /// Returns all of the item's dates, each with what it's the date of, like
+    /// `"completed"`, for checking they're valid.
+    fn dates(&self) -> Vec<(&'static str, KiraDate)>;
+
+    /// Returns the item's text, each with the field it's from, like
+    /// `"description"`, for checking none of it is empty or left as a
+    /// placeholder. The text of its [`media`](Self::media) is checked too.
+    fn texts(&self) -> Vec<(&'static str, Localised)>;
+
+    /// Returns the keys of the item's parts that are rendered side by side,
+    /// like the addresses of its media, which Yew needs to be unique.
+    fn keys(&self) -> Vec<(&'static str, String)> {
+        self.media()
+            .iter()
+            .map(|media| ("media", media.src.clone()))
+            .collect()
+    }
+
+    /// Returns the [`Area`]s the item is in.
+    fn areas(&self) -> Vec<Area>;
+
+    /// Returns the [`Tool`]s the item was made with.
+    fn tools(&self) -> Vec<Tool> {
+        Vec::new()
+    }
+
+    /// Returns the [`Skill`]s the item uses.
+    fn skills(&self) -> &[Rc<Skill>] {
+        &[]
+    }
+
+    /// Returns the images, videos and audio attached to the item.
+    fn media(&self) -> &[Media] {
+        &[]
+    }
+
+    /// Returns the item's card, titled with a heading of the given `level`.
+    ///
+    /// Cards below the top level are in lists, so show a thumbnail of the
+    /// item's media; the item's own page shows all of it in a [`Gallery`]
+    /// instead.
+    fn card_to_html(&self, level: u8) -> Html;
+}
+
+// ********************************* Errors ********************************* //
+// ************************************************************************** //
+
+/// The ways content can be broken, found while building the content lists.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContentError {
+    /// A reference from the item `from`, in `from_list`, to an item that
+    /// isn't in its list.
+    Missing {
+        list: &'static str,
+        name: String,
+        from_list: &'static str,
+        from: String,
+    },
+    /// Items in a list with the same name, and so the same [`Id`].
+    Duplicate { list: &'static str, name: String },
+    /// An item with a date that doesn't exist, like the 31st of February.
+    InvalidDate {
+        list: &'static str,
+        name: String,
+        date: KiraDate,
+    },
+}
+
+impl Display for ContentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing {
+                list,
+                name,
+                from_list,
+                from,
+            } => write!(
+                f,
+                "`{from}` in the {from_list} references `{name}`, which isn't in the {list}"
+            ),
+            Self::Duplicate { list, name } => {
+                write!(f, "`{name}` is in the {list} more than once")
+            }
+            Self::InvalidDate { list, name, date } => write!(
+                f,
+                "`{name}` in the {list} has an invalid date, {}",
+                date.to_iso_string()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ContentError {}
+
+/// Returns an error for every name that repeats an earlier one.
+fn duplicate_names<'a>(
+    list: &'static str,
+    names: impl IntoIterator<Item = &'a str>,
+) -> Vec<ContentError> {
+    let mut seen = HashSet::new();
+
+    names
+        .into_iter()
+        .filter(|name| !seen.insert(*name))
+        .map(|name| ContentError::Duplicate {
+            list,
+            name: name.to_string(),
+        })
+        .collect()
+}
+
+/// Returns an error for every one of an item's dates that is invalid.
+fn invalid_dates(
+    list: &'static str,
+    name: &str,
+    dates: impl IntoIterator<Item = KiraDate>,
+) -> Vec<ContentError> {
+    dates
+        .into_iter()
+        .filter(|date| !date.is_valid())
+        .map(|date| ContentError::InvalidDate {
+            list,
+            name: name.to_string(),
+            date,
+        })
+        .collect()
+}
+
+/// Returns `value`, or `errors` if there are any.
+fn valid<T>(value: T, errors: Vec<ContentError>) -> Result<T, Vec<ContentError>> {
+    if errors.is_empty() {
+        Ok(value)
+    } else {
+        Err(errors)
+    }
+}
+
+// ************ Headings ************ //
+// ********************************** //
+
+/// Returns a heading of the given `level`, clamped to `<h1>` to `<h6>`, so
+/// that the same markup can sit at any depth of a page's outline without
+/// skipping levels.
+#[allow(clippy::ignored_unit_patterns)]
+pub fn heading(level: u8, classes: Classes, children: Html) -> Html {
+    let tag = format!("h{}", level.clamp(1, 6));
+
+    html! {
+        <@{tag} class={classes}>{children}</@>
+    }
+}
+
+// *********** Date Ranges ********** //
+// ********************************** //
+
+/// Returns the span from `started` until `ended`, or until the present if
+/// `ended` is `None`, like "Sep 2021 - present".
+pub fn date_range(started: KiraDate, ended: Option<KiraDate>) -> Html {
+    let ended = ended.map_or_else(
+        || html! { {Locale::current().messages().present} },
+        |ended| html! { <FormattedDate date={ended} /> },
+    );
+
+    html! {
+        <>
+            <FormattedDate date={started} />
+            {" - "}
+            {ended}
+        </>
     }
 }
 
@@ -53,15 +759,56 @@ pub trait Get<K, V> {
 // ********************************** //
 
 /// An area, like an "area of interest". Similar to a [`Topic`].
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Area(pub &'static str);
 
+impl Area {
+    /// Returns the last part of the area's name, like `web` for
+    /// `development/web`.
+    pub fn short_name(self) -> &'static str {
+        self.0.rsplit_once('/').map_or(self.0, |(_, name)| name)
+    }
+
+    /// Returns the area this one is part of, like `development` for
+    /// `development/web`, or `None` if it's a top-level area.
+    pub fn parent(self) -> Option<Self> {
+        self.0.rsplit_once('/').map(|(parent, _)| Self(parent))
+    }
+
+    /// Returns the areas this one is part of, outermost first.
+    pub fn ancestors(self) -> Vec<Self> {
+        let mut ancestors =
+            std::iter::successors(self.parent(), |area| area.parent()).collect::<Vec<Self>>();
+        ancestors.reverse();
+        ancestors
+    }
+
+    /// Returns `true` if the area is `other` or one of its sub-areas.
+    pub fn is_within(self, other: Self) -> bool {
+        self.0
+            .strip_prefix(other.0)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    }
+}
+
 impl Display for Area {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
     }
 }
 
+impl ToHtml for Area {
+    /// Returns a link to the area's page.
+    fn to_html(&self) -> Html {
+        html! {
+            <span>
+                <Link<Route> to={Route::Area { area: self.0.to_string() }}>{self.to_string()}</Link<Route>>
+                {". "}
+            </span>
+        }
+    }
+}
+
 impl NotFoundValue for Area {
     fn not_found() -> Self {
         Self("<ERR: Area not found>")
@@ -69,14 +816,29 @@ impl NotFoundValue for Area {
 }
 
 /// A list of [`Area`]s with `get`ting functionality.
-///
-/// _potential TODO: implement hashing function for getters_
 #[derive(Clone, PartialEq, Eq)]
 pub struct AreaList {
     areas: Vec<Area>,
+    lookup: Lookup<Area>,
 }
 
 impl AreaList {
+    /// Returns the list, or an error for each area listed more than once.
+    ///
+    /// # Errors
+    ///
+    /// Fails if any area is repeated.
+    pub fn try_new(areas: Vec<Area>) -> Result<Self, Vec<ContentError>> {
+        let list = Self::from(areas);
+        let errors = list.errors();
+        valid(list, errors)
+    }
+
+    /// Returns an error for each area listed more than once.
+    pub fn errors(&self) -> Vec<ContentError> {
+        duplicate_names(Self::LIST, self.areas.iter().map(|area| area.0))
+    }
+
     /// Returns an iterator over the areas in the list.
     pub fn iter(&self) -> impl Iterator<Item = &Area> {
         self.areas.iter()
@@ -84,29 +846,33 @@ impl AreaList {
 }
 
 impl Get<&str, Area> for AreaList {
+    const LIST: &'static str = "areas";
+
     /// Finds the first area in the list with the given name (`string`), or
     /// `None` if no match is found.
     ///
-    /// Returns an `Option<&Area>`
+    /// Returns an `Option<Area>`
     fn get(&self, string: &str) -> Option<Area> {
-        self.iter().find(|area| area.to_string() == string).copied()
+        self.get_by_name(string).copied()
     }
+}
 
-    /// Finds the first area in the list with the given name (`string`), or
-    /// panics with the message below if no match is found.
-    ///
-    /// **Message:** ``Could not find Area `{string}` in AreaList``
-    ///
-    /// Where the "{string}" is the given `string` parameter.
-    fn get_unwrap(&self, string: &str) -> Area {
-        self.get(string)
-            .unwrap_or_else(|| panic!("Could not find Area `{string}` in AreaList"))
+impl Indexed<Area> for AreaList {
+    fn lookup(&self) -> &Lookup<Area> {
+        &self.lookup
+    }
+
+    fn items(&self) -> &[Area] {
+        &self.areas
     }
 }
 
 impl From<Vec<Area>> for AreaList {
     fn from(areas: Vec<Area>) -> Self {
-        Self { areas }
+        Self {
+            lookup: Lookup::new(areas.iter().map(|area| area.0)),
+            areas,
+        }
     }
 }
 
@@ -131,14 +897,29 @@ impl NotFoundValue for Tool {
 }
 
 /// A list of [`Tool`]s with `get`ting functionality.
-///
-/// _potential TODO: implement hashing function for getters_
 #[derive(Clone, PartialEq, Eq)]
 pub struct ToolList {
     tools: Vec<Tool>,
+    lookup: Lookup<Tool>,
 }
 
 impl ToolList {
+    /// Returns the list, or an error for each tool listed more than once.
+    ///
+    /// # Errors
+    ///
+    /// Fails if any tool is repeated.
+    pub fn try_new(tools: Vec<Tool>) -> Result<Self, Vec<ContentError>> {
+        let list = Self::from(tools);
+        let errors = list.errors();
+        valid(list, errors)
+    }
+
+    /// Returns an error for each tool listed more than once.
+    pub fn errors(&self) -> Vec<ContentError> {
+        duplicate_names(Self::LIST, self.tools.iter().map(|tool| tool.0))
+    }
+
     /// Returns an iterator over the tools in the list.
     pub fn iter(&self) -> impl Iterator<Item = &Tool> {
         self.tools.iter()
@@ -146,29 +927,33 @@ impl ToolList {
 }
 
 impl Get<&str, Tool> for ToolList {
+    const LIST: &'static str = "tools";
+
     /// Finds the first tool in the list with the given name (`string`), or
     /// `None` if no match is found.
     ///
     /// Returns an `Option<Tool>`
     fn get(&self, string: &str) -> Option<Tool> {
-        self.iter().find(|tool| tool.to_string() == string).copied()
+        self.get_by_name(string).copied()
     }
+}
 
-    /// Finds the first tool in the list with the given name (`string`), or
-    /// panics with the message below if no match is found.
-    ///
-    /// **Message:** ``Could not find Tool `{string}` in ToolList``
-    ///
-    /// Where the "{string}" is the given `string` parameter.
-    fn get_unwrap(&self, string: &str) -> Tool {
-        self.get(string)
-            .unwrap_or_else(|| panic!("Could not find Tool `{string}` in ToolList"))
+impl Indexed<Tool> for ToolList {
+    fn lookup(&self) -> &Lookup<Tool> {
+        &self.lookup
+    }
+
+    fn items(&self) -> &[Tool] {
+        &self.tools
     }
 }
 
 impl From<Vec<Tool>> for ToolList {
     fn from(tools: Vec<Tool>) -> Self {
-        Self { tools }
+        Self {
+            lookup: Lookup::new(tools.iter().map(|tool| tool.0)),
+            tools,
+        }
     }
 }
 
@@ -198,7 +983,7 @@ impl Display for Topic {
 // ********** Competencies ********** //
 // ********************************** //
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(usize)]
 pub enum Competency {
     None = 0,
@@ -209,21 +994,50 @@ pub enum Competency {
 }
 
 impl Display for Competency {
+    /// Writes the competency's name in the current locale.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::None => "None",
-                Self::Novice => "Novice",
-                Self::Intermediate => "Intermediate",
-                Self::Advanced => "Advanced",
-                Self::Expert => "Expert",
-            }
-        )
+        write!(f, "{}", Locale::current().messages().competency_name(*self))
+    }
+}
+
+impl Competency {
+    /// The highest competency level, used as the maximum of meters and charts.
+    pub const MAX: Self = Self::Expert;
+
+    /// Returns the competency as a percentage of [`Competency::MAX`].
+    pub fn percentage(self) -> usize {
+        usize::from(self) * 100 / usize::from(Self::MAX)
+    }
+}
+
+impl ToHtml for Competency {
+    fn to_html(&self) -> Html {
+        let name = Locale::current().messages().competency_name(*self);
+
+        html! {
+            <span class={"competency"}>
+                <meter
+                    class={"competency-meter"}
+                    min={"0"}
+                    max={usize::from(Self::MAX).to_string()}
+                    value={usize::from(*self).to_string()}
+                    title={name}
+                >
+                    {name}
+                </meter>
+                {" "}{name}
+            </span>
+        }
     }
 }
 
+/// A dated competency level, used to record a skill's progress over time.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CompetencyChange {
+    pub date: KiraDate,
+    pub competency: Competency,
+}
+
 impl From<Competency> for usize {
     fn from(competency: Competency) -> Self {
         competency as Self
@@ -250,10 +1064,13 @@ impl From<usize> for Competency {
 #[allow(clippy::module_name_repetitions)]
 #[derive(Clone, PartialEq)]
 pub enum Content {
-    Skill(Skill),
+    Skill(Skill, SkillUsage),
     Achievement(Achievement),
     Creation(Creation),
+    Project(Project),
     Article(Article),
+    Education(Education),
+    Experience(Experience),
     NotFound,
 }
 
@@ -269,185 +1086,825 @@ pub struct ContentProperties {
     pub content: Content,
 }
 
+/// The page for a single content item, with the item's name as its heading.
 #[function_component(ContentComponent)]
 pub fn content_component(ContentProperties { content }: &ContentProperties) -> Html {
     match content {
-        Content::Skill(skill) => skill.to_html(),
-        Content::Achievement(achievement) => achievement.to_html(),
-        Content::Creation(creation) => creation.to_html(),
-        Content::Article(article) => article.to_html(),
+        Content::Skill(skill, usage) => html! {
+            <>
+                {skill.card_to_html(1)}
+                {skill.history_to_html()}
+                {usage.to_html()}
+            </>
+        },
+        Content::Achievement(achievement) => html! {
+            <>
+                {achievement.card_to_html(1)}
+                <Gallery key={achievement.id().to_string()} media={achievement.media.clone()} />
+            </>
+        },
+        Content::Creation(creation) => html! {
+            <>
+                {creation.card_to_html(1)}
+                <Gallery key={creation.id().to_string()} media={creation.media.clone()} />
+            </>
+        },
+        Content::Project(project) => html! {
+            <>
+                {project.card_to_html(1)}
+                <Gallery key={project.id().to_string()} media={project.media.clone()} />
+                {project.releases_to_html()}
+            </>
+        },
+        Content::Article(article) => article.card_to_html(1),
+        Content::Education(education) => education.card_to_html(1),
+        Content::Experience(experience) => experience.card_to_html(1),
         Content::NotFound => html! { <NotFound /> },
     }
 }
 
-// ************* Skills ************* //
+// ********** Content Lists ********* //
 // ********************************** //
 
+/// A list of [`ContentItem`]s, indexed when it's built.
+#[allow(clippy::module_name_repetitions)]
 #[derive(Clone, PartialEq, Eq)]
-pub struct Skill {
-    pub id: usize,
-    pub name: String,
-    pub areas: Vec<Rc<Area>>,
-    pub competency: Competency,
-    pub description: String,
+pub struct ContentList<T: ContentItem> {
+    items: Vec<T>,
+    lookup: Lookup<T>,
 }
 
-impl ToHtml for Skill {
-    fn to_html(&self) -> Html {
-        html! {
-            <div key={self.id} class={"card"}>
-                <h3 class={"card-title"}>{self.name.clone()}</h3>
-                <p class={"card-detail"}>
-                    {self.areas.iter().map(|area| html!{<span>{area.to_string() + ". "}</span>}).collect::<Html>()}
-                </p>
-                <p class={"card-detail"}>{format!("Competency: {}", self.competency)}</p>
-                <p class={"card-text"}>{self.description.clone()}</p>
-            </div>
+impl<T: ContentItem> ContentList<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        Self {
+            lookup: Lookup::new(items.iter().map(ContentItem::name)),
+            items,
         }
     }
-}
 
-impl NotFoundValue for Skill {
-    fn not_found() -> Self {
-        Self {
-            id: usize::MAX,
-            name: String::from("<ERR: Skill not found>"),
-            areas: Vec::new(),
-            competency: Competency::None,
-            description: String::from("<An Error Occurred - This Skill was not found>"),
+    /// Returns the list, or an error for each item sharing an earlier item's
+    /// name and each invalid date.
+    ///
+    /// # Errors
+    ///
+    /// Fails if any item has a repeated name or an invalid date.
+    pub fn try_new(items: Vec<T>) -> Result<Self, Vec<ContentError>> {
+        let list = Self::new(items);
+        let errors = list.errors();
+        valid(list, errors)
+    }
+
+    /// Returns an error for each item sharing an earlier item's name and each
+    /// invalid date.
+    pub fn errors(&self) -> Vec<ContentError> {
+        let mut errors = duplicate_names(T::LIST, self.items.iter().map(ContentItem::name));
+        for item in &self.items {
+            errors.extend(invalid_dates(
+                T::LIST,
+                item.name(),
+                item.dates().into_iter().map(|(_, date)| date),
+            ));
         }
+
+        errors
     }
-}
 
-#[derive(Properties, PartialEq, Eq)]
-pub struct SkillProperties {
-    pub skill: Skill,
-}
+    /// Returns an iterator over the items in the list.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter()
+    }
 
-#[derive(Clone, PartialEq, Eq)]
-pub struct SkillList {
-    skills: Vec<Skill>,
+    /// Returns the number of items in the list.
+    pub const fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the list has no items.
+    pub const fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the items sorted in the given order. Ties keep their declared
+    /// order.
+    pub fn sorted(&self, order: ContentOrder) -> Vec<&T> {
+        let mut items = self.items.iter().collect::<Vec<&T>>();
+
+        match order {
+            ContentOrder::Declared => {}
+            ContentOrder::Newest => items.sort_by_key(|item| std::cmp::Reverse(item.date())),
+            ContentOrder::Oldest => items.sort_by_key(|item| item.date()),
+            ContentOrder::Name => items.sort_by_key(|item| item.name().to_lowercase()),
+        }
+
+        items
+    }
+
+    /// Returns the items matching `predicate`, in declared order.
+    pub fn filtered(&self, predicate: impl Fn(&T) -> bool) -> Vec<&T> {
+        self.items.iter().filter(|item| predicate(item)).collect()
+    }
+
+    /// Returns the items in the given area.
+    pub fn with_area(&self, area: Area) -> Vec<&T> {
+        self.filtered(|item| item.areas().contains(&area))
+    }
+
+    /// Returns the items in the given area or any of its sub-areas.
+    pub fn within_area(&self, area: Area) -> Vec<&T> {
+        self.filtered(|item| item.areas().iter().any(|own| own.is_within(area)))
+    }
+
+    /// Returns the items made with the given tool.
+    pub fn with_tool(&self, tool: Tool) -> Vec<&T> {
+        self.filtered(|item| item.tools().contains(&tool))
+    }
+
+    /// Returns the items using the skill with the given name.
+    pub fn with_skill(&self, name: &str) -> Vec<&T> {
+        self.filtered(|item| item.skills().iter().any(|skill| skill.name == name))
+    }
+
+    /// Returns the items whose name, areas, tools or skills contain `query`,
+    /// ignoring case. An empty query matches every item.
+    pub fn search(&self, query: &str) -> Vec<&T> {
+        let query = query.trim().to_lowercase();
+        let matches = |text: &str| text.to_lowercase().contains(&query);
+
+        self.filtered(|item| {
+            matches(item.name())
+                || item.areas().iter().any(|area| matches(area.0))
+                || item.tools().iter().any(|tool| matches(tool.0))
+                || item.skills().iter().any(|skill| matches(&skill.name))
+        })
+    }
+
+    /// Returns the items on the given page, counting from `0`, when the list
+    /// is split into pages of `size` items. Pages past the end are empty.
+    pub fn page(&self, number: usize, size: usize) -> &[T] {
+        let start = number.saturating_mul(size).min(self.items.len());
+        let end = start.saturating_add(size).min(self.items.len());
+
+        &self.items[start..end]
+    }
+
+    /// Returns the number of pages of `size` items the list splits into.
+    pub fn page_count(&self, size: usize) -> usize {
+        self.items.len().div_ceil(size.max(1))
+    }
 }
 
-impl SkillList {
-    pub fn new(skills: Vec<Skill>) -> Self {
-        Self { skills }
+impl<T: ContentItem> ContentList<T> {
+    /// Returns an [`Html`] timeline of the list, most recent first.
+    pub fn timeline_to_html(&self) -> Html {
+        html! {
+            <ol class={"timeline"}>
+                {self.sorted(ContentOrder::Newest).into_iter().map(|item| html!{
+                    <li key={item.id()} class={"timeline-entry"}>{item.card_to_html(2)}</li>
+                }).collect::<Html>()}
+            </ol>
+        }
     }
 }
 
-impl Index<usize> for SkillList {
-    type Output = Skill;
+impl<T: ContentItem> Index<usize> for ContentList<T> {
+    type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
-        &self.skills[index]
+        &self.items[index]
+    }
+}
+
+impl<T: ContentItem> Get<&str, T> for ContentList<T> {
+    const LIST: &'static str = T::LIST;
+
+    fn get(&self, name: &str) -> Option<T> {
+        self.get_by_name(name).cloned()
     }
 }
 
-impl Get<&str, Skill> for SkillList {
-    fn get(&self, name: &str) -> Option<Skill> {
-        self.skills.iter().find(|skill| skill.name == name).cloned()
+impl<T: ContentItem> Indexed<T> for ContentList<T> {
+    fn lookup(&self) -> &Lookup<T> {
+        &self.lookup
+    }
+
+    fn items(&self) -> &[T] {
+        &self.items
     }
 }
 
-impl ToHtml for SkillList {
+impl<T: ContentItem> ToHtml for ContentList<T> {
     fn to_html(&self) -> Html {
         html! {
             <div class={"content-list"}>
-                {self.skills.iter().map(ToHtml::to_html).collect::<Html>()}
+                {self.items.iter().map(|item| item.card_to_html(2)).collect::<Html>()}
             </div>
         }
     }
 }
 
-#[derive(Properties, PartialEq, Eq)]
-pub struct SkillListProperties {
-    pub skills: SkillList,
+/// The orders a [`ContentList`] can be sorted in.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentOrder {
+    /// The order the items were declared in.
+    Declared,
+    /// Most recent first.
+    Newest,
+    /// Least recent first.
+    Oldest,
+    /// Alphabetically by name, ignoring case.
+    Name,
 }
 
-// ********** Achievements ********** //
+// ************* Skills ************* //
 // ********************************** //
 
 #[derive(Clone, PartialEq, Eq)]
-pub struct Achievement {
-    pub id: usize,
+pub struct Skill {
     pub name: String,
-    pub completed: KiraDate,
     pub areas: Vec<Rc<Area>>,
-    pub tools: Vec<Rc<Tool>>,
-    pub skills: Vec<Rc<Skill>>,
-    pub description: String,
+    pub competency: Competency,
+    /// Past competency levels, oldest first.
+    pub history: Vec<CompetencyChange>,
+    /// A grouping for the skill beyond its [`Area`]s, like "Languages".
+    pub category: Option<String>,
+    /// When the skill was first used, or `None` if it isn't known, in which
+    /// case no experience is shown.
+    pub started: Option<KiraDate>,
+    /// When the skill was last used, or `None` if it is still in use.
+    pub last_used: Option<KiraDate>,
+    pub description: Localised,
 }
 
-impl ToHtml for Achievement {
-    fn to_html(&self) -> Html {
+impl Skill {
+    /// Returns the date the skill was last used, or today if it is still in
+    /// use.
+    pub fn last_active(&self) -> KiraDate {
+        self.last_used.unwrap_or_else(KiraDate::today)
+    }
+
+    /// Returns the number of whole years the skill has been used for, or
+    /// `None` if it isn't known when it was started.
+    pub fn years_of_experience(&self) -> Option<Year> {
+        self.started
+            .map(|started| started.years_until(self.last_active()))
+    }
+
+    /// Returns a human-readable description of the skill's experience in the
+    /// current locale, like "3 years (2021 - present)", or `None` if it isn't
+    /// known when it was started.
+    pub fn experience_to_string(&self) -> Option<String> {
+        let messages = Locale::current().messages();
+        let started = self.started?;
+        let years = (messages.years)(started.years_until(self.last_active()));
+        let until = self.last_used.map_or_else(
+            || messages.present.to_string(),
+            |date| date.get_year().to_string(),
+        );
+
+        Some(format!("{years} ({} - {until})", started.get_year()))
+    }
+
+    /// Returns an [`Html`] timeline of the skill's competency history, or
+    /// nothing if none has been recorded.
+    pub fn history_to_html(&self) -> Html {
+        let messages = Locale::current().messages();
+
+        if self.history.is_empty() {
+            return html! {};
+        }
+
         html! {
-            <div key={self.id} class={"card"}>
-                <h3 class={"card-title"}>{self.name.clone()}</h3>
-                <p class={"card-detail"}>{
-                    self.areas.iter().map(|area| html!{
-                        <span>{area.to_string() + ". "}</span>
+            <div class={"competency-history"}>
+                <h2>{messages.progress}</h2>
+                <ol class={"competency-chart"}>{
+                    self.history.iter().map(|change| html!{
+                        <li class={"competency-chart-row"}>
+                            <span class={"competency-chart-label"}>
+                                <FormattedDate date={change.date} />
+                            </span>
+                            <span
+                                class={"competency-chart-bar"}
+                                style={format!("width: {}%;", change.competency.percentage())}
+                            ></span>
+                            <span class={"competency-chart-value"}>
+                                {messages.competency_name(change.competency)}
+                            </span>
+                        </li>
                     }).collect::<Html>()
-                }</p>
-                <p class={"card-detail"}>{self.completed.to_string()}</p>
-                <p class={"card-text"}>{self.description.clone()}</p>
-                <p class={"card-detail"}>{
-                    self.tools.iter().map(|tool| html!{
-                        <span>{tool.to_string() + ". "}</span>
-                    }).collect::<Html>()
-                }</p>
-                <p class={"card-detail"}>{
-                    self.skills.iter().map(|skill| html!{
-                        <span>{skill.name.clone() + ". "}</span>
-                    }).collect::<Html>()
-                }</p>
+                }</ol>
             </div>
         }
     }
 }
 
+impl ContentItem for Skill {
+    const LIST: &'static str = "skills";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns when the skill was started, or last used if that isn't known.
+    fn date(&self) -> KiraDate {
+        self.started.unwrap_or_else(|| self.last_active())
+    }
+
+    fn dates(&self) -> Vec<(&'static str, KiraDate)> {
+        self.started
+            .map(|date| ("started", date))
+            .into_iter()
+            .chain(self.last_used.map(|date| ("last used", date)))
+            .chain(self.history.iter().map(|change| ("history", change.date)))
+            .collect()
+    }
+
+    fn texts(&self) -> Vec<(&'static str, Localised)> {
+        let mut texts = vec![("name", Localised::from(self.name.as_str()))];
+        texts.extend(
+            self.category
+                .as_deref()
+                .map(|category| ("category", Localised::from(category))),
+        );
+        texts.push(("description", self.description.clone()));
+        texts
+    }
+
+    fn areas(&self) -> Vec<Area> {
+        self.areas.iter().map(|area| **area).collect()
+    }
+
+    fn card_to_html(&self, level: u8) -> Html {
+        let messages = Locale::current().messages();
+
+        html! {
+            <article key={self.id()} class={"card"}>
+                {heading(level, classes!("card-title"), html! {self.name.clone()})}
+                <p class={"card-detail"}>
+                    {self.areas.iter().map(|area| area.to_html()).collect::<Html>()}
+                </p>
+                <p class={"card-detail"}>{messages.competency}{self.competency.to_html()}</p>
+                if let Some(experience) = self.experience_to_string() {
+                    <p class={"card-detail"}>{messages.experience}{experience}</p>
+                }
+                <p class={"card-text"}>{self.description.to_string()}</p>
+            </article>
+        }
+    }
+}
+
+impl ToHtml for Skill {
+    fn to_html(&self) -> Html {
+        self.card_to_html(2)
+    }
+}
+
+impl NotFoundValue for Skill {
+    fn not_found() -> Self {
+        Self {
+            name: String::from("<ERR: Skill not found>"),
+            areas: Vec::new(),
+            competency: Competency::None,
+            history: Vec::new(),
+            category: None,
+            started: None,
+            last_used: None,
+            description: Localised::from("<An Error Occurred - This Skill was not found>"),
+        }
+    }
+}
+
 #[derive(Properties, PartialEq, Eq)]
-pub struct AchievementProperties {
-    pub achievement: Achievement,
+pub struct SkillProperties {
+    pub skill: Skill,
 }
 
-#[derive(Clone, PartialEq, Eq)]
-pub struct AchievementList {
-    achievements: Vec<Achievement>,
+/// The achievements, creations, projects, education and experience that
+/// reference a [`Skill`], by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SkillUsage {
+    pub achievements: Vec<String>,
+    pub creations: Vec<String>,
+    pub projects: Vec<String>,
+    pub education: Vec<String>,
+    pub experience: Vec<String>,
 }
 
-impl AchievementList {
-    pub fn new(achievements: Vec<Achievement>) -> Self {
-        Self { achievements }
+impl SkillUsage {
+    /// Returns a short summary of the usage counts in the current locale, like
+    /// "Used in 1 achievement and 4 creations.", or `None` if the skill is
+    /// unused.
+    pub fn summary(&self) -> Option<String> {
+        let messages = Locale::current().messages();
+        let counts = [
+            (self.achievements.len(), messages.achievement_count),
+            (self.creations.len(), messages.creation_count),
+            (self.projects.len(), messages.project_count),
+            (self.education.len(), messages.qualification_count),
+            (self.experience.len(), messages.role_count),
+        ]
+        .into_iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, noun)| noun(count))
+        .collect::<Vec<String>>();
+
+        if counts.is_empty() {
+            None
+        } else {
+            Some((messages.used_in)(&counts))
+        }
     }
 }
 
-impl Index<usize> for AchievementList {
-    type Output = Achievement;
+impl ToHtml for SkillUsage {
+    fn to_html(&self) -> Html {
+        let messages = Locale::current().messages();
 
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.achievements[index]
+        html! {
+            <div class={"skill-usage"}>
+                {usage_to_html(
+                    messages.achievements,
+                    messages.no_achievements,
+                    &self.achievements,
+                    |achievement| Route::Achievement { achievement },
+                )}
+                {usage_to_html(
+                    messages.creations,
+                    messages.no_creations,
+                    &self.creations,
+                    |creation| Route::Creation { creation },
+                )}
+                {usage_to_html(
+                    messages.projects,
+                    messages.no_projects,
+                    &self.projects,
+                    |project| Route::Project { project },
+                )}
+                {usage_to_html(
+                    messages.education,
+                    messages.no_qualifications,
+                    &self.education,
+                    |qualification| Route::Qualification { qualification },
+                )}
+                {usage_to_html(
+                    messages.experiences,
+                    messages.no_roles,
+                    &self.experience,
+                    |role| Route::Role { role },
+                )}
+            </div>
+        }
     }
 }
 
-impl Get<&str, Achievement> for AchievementList {
-    fn get(&self, name: &str) -> Option<Achievement> {
-        self.achievements
-            .iter()
-            .find(|achievement| achievement.name == name)
-            .cloned()
+/// Returns a heading over links to the named items, or the text for when
+/// there are none.
+fn usage_to_html(
+    heading: &'static str,
+    empty: &'static str,
+    names: &[String],
+    route: fn(String) -> Route,
+) -> Html {
+    html! {
+        <>
+            <h2>{heading}</h2>
+            if names.is_empty() {
+                <p class={"card-detail"}>{empty}</p>
+            } else {
+                <ul>{
+                    names.iter().map(|name| html!{
+                        <li>
+                            <Link<Route> to={route(name.clone())}>{name.clone()}</Link<Route>>
+                        </li>
+                    }).collect::<Html>()
+                }</ul>
+            }
+        </>
     }
 }
 
-impl ToHtml for AchievementList {
-    fn to_html(&self) -> Html {
+/// A reverse index of the achievements, creations, projects, education and
+/// experience referencing each [`Skill`], keyed by the skill's id.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SkillUsages {
+    usage: HashMap<Id<Skill>, SkillUsage>,
+}
+
+impl SkillUsages {
+    /// Returns the index of the skills referenced by the given achievements,
+    /// creations, projects, education and experience.
+    ///
+    /// This is meant to be called once, after all of the lists are built.
+    pub fn new(
+        achievements: &AchievementList,
+        creations: &CreationList,
+        projects: &ProjectList,
+        education: &EducationList,
+        experience: &ExperienceList,
+    ) -> Self {
+        let mut usages = Self::default();
+
+        usages.index(achievements, |usage| &mut usage.achievements);
+        usages.index(creations, |usage| &mut usage.creations);
+        usages.index(projects, |usage| &mut usage.projects);
+        usages.index(education, |usage| &mut usage.education);
+        usages.index(experience, |usage| &mut usage.experience);
+
+        usages
+    }
+
+    /// Adds the names of the items in `list` to the usage of each skill they
+    /// reference, in the list of names `field` picks.
+    fn index<T: ContentItem>(
+        &mut self,
+        list: &ContentList<T>,
+        field: fn(&mut SkillUsage) -> &mut Vec<String>,
+    ) {
+        for item in list.iter() {
+            for skill in item.skills() {
+                field(self.usage.entry(skill.id()).or_default()).push(item.name().to_string());
+            }
+        }
+    }
+
+    /// Returns the achievements, creations, projects, education and
+    /// experience referencing the given skill.
+    pub fn get(&self, skill: &Skill) -> SkillUsage {
+        self.usage.get(&skill.id()).cloned().unwrap_or_default()
+    }
+}
+
+/// A list of [`Skill`]s.
+pub type SkillList = ContentList<Skill>;
+
+impl ContentList<Skill> {
+    /// Returns the skills grouped by [`Area`], in order of each area's first
+    /// appearance. Skills with several areas appear in each of their groups.
+    pub fn by_area(&self) -> Vec<(Area, Vec<&Skill>)> {
+        let mut groups: Vec<(Area, Vec<&Skill>)> = Vec::new();
+
+        for skill in &self.items {
+            for area in &skill.areas {
+                match groups.iter_mut().find(|(group, _)| group == area.as_ref()) {
+                    Some((_, skills)) => skills.push(skill),
+                    None => groups.push((**area, vec![skill])),
+                }
+            }
+        }
+
+        groups
+    }
+
+    /// Returns the skills grouped by [`Competency`], most competent first.
+    /// Competencies with no skills are left out.
+    pub fn by_competency(&self) -> Vec<(Competency, Vec<&Skill>)> {
+        let mut groups: Vec<(Competency, Vec<&Skill>)> = Vec::new();
+
+        for skill in self.sorted_skills(SkillOrder::Competency) {
+            match groups.last_mut() {
+                Some((competency, skills)) if *competency == skill.competency => skills.push(skill),
+                _ => groups.push((skill.competency, vec![skill])),
+            }
+        }
+
+        groups
+    }
+
+    /// Returns the skills sorted in the given order. Ties keep their declared
+    /// order.
+    pub fn sorted_skills(&self, order: SkillOrder) -> Vec<&Skill> {
+        let mut skills = self.items.iter().collect::<Vec<&Skill>>();
+
+        match order {
+            SkillOrder::Declared => {}
+            SkillOrder::Competency => {
+                skills.sort_by_key(|skill| std::cmp::Reverse(skill.competency));
+            }
+            SkillOrder::Recency => {
+                skills.sort_by_key(|skill| std::cmp::Reverse(skill.last_active()));
+            }
+        }
+
+        skills
+    }
+
+    /// Returns the skills sorted in the given order, grouped by category in
+    /// order of each category's first appearance. Uncategorised skills are
+    /// grouped under `None`, last.
+    pub fn by_category(&self, order: SkillOrder) -> Vec<(Option<String>, Vec<&Skill>)> {
+        let mut groups: Vec<(Option<String>, Vec<&Skill>)> = Vec::new();
+
+        for skill in self.sorted_skills(order) {
+            match groups
+                .iter_mut()
+                .find(|(category, _)| *category == skill.category)
+            {
+                Some((_, skills)) => skills.push(skill),
+                None => groups.push((skill.category.clone(), vec![skill])),
+            }
+        }
+
+        groups.sort_by_key(|(category, _)| category.is_none());
+        groups
+    }
+
+    /// Returns a skill's card, titled with a heading of the given `level`,
+    /// along with its usage summary.
+    fn card_to_html(skill: &Skill, level: u8, usages: &SkillUsages) -> Html {
         html! {
-            <div class={"content-list"}>
-                {self.achievements.iter().map(ToHtml::to_html).collect::<Html>()}
+            <div key={skill.id()}>
+                {skill.card_to_html(level)}
+                if let Some(summary) = usages.get(skill).summary() {
+                    <p class={"card-detail"}>{summary}</p>
+                }
             </div>
         }
     }
+
+    /// Returns an [`Html`] representation of the list, sorted in the given
+    /// order and optionally grouped by category, with each skill's usage.
+    pub fn to_html_arranged(&self, order: SkillOrder, grouped: bool, usages: &SkillUsages) -> Html {
+        if grouped {
+            self.by_category(order)
+                .into_iter()
+                .map(|(category, skills)| {
+                    let category = category.unwrap_or_else(|| {
+                        Locale::current().messages().other_category.to_string()
+                    });
+
+                    html! {
+                        <section key={category.clone()}>
+                            <h2>{category}</h2>
+                            <div class={"content-list"}>
+                                {skills.into_iter().map(|skill| Self::card_to_html(skill, 3, usages)).collect::<Html>()}
+                            </div>
+                        </section>
+                    }
+                })
+                .collect::<Html>()
+        } else {
+            html! {
+                <div class={"content-list"}>
+                    {self.sorted_skills(order).into_iter().map(|skill| Self::card_to_html(skill, 2, usages)).collect::<Html>()}
+                </div>
+            }
+        }
+    }
+}
+
+/// The orders a [`SkillList`] can be displayed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillOrder {
+    /// The order the skills were declared in.
+    Declared,
+    /// Most competent first.
+    Competency,
+    /// Most recently used first.
+    Recency,
+}
+
+impl SkillOrder {
+    /// Every order, for building controls.
+    pub const ALL: [Self; 3] = [Self::Declared, Self::Competency, Self::Recency];
+}
+
+impl Display for SkillOrder {
+    /// Writes the order's name in the current locale.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Locale::current().messages().skill_order(*self))
+    }
+}
+
+#[derive(Properties, PartialEq, Eq)]
+pub struct SkillListProperties {
+    pub skills: SkillList,
+}
+
+/// A bar chart of each skill's competency, grouped by [`Area`].
+#[function_component(CompetencyChart)]
+pub fn competency_chart(SkillListProperties { skills }: &SkillListProperties) -> Html {
+    let messages = Locale::current().messages();
+
+    html! {
+        <section class={"competency-chart-container"}>
+            <h2>{messages.competency_by_area}</h2>
+            {skills.by_area().into_iter().map(|(area, skills)| html!{
+                <section key={area.0} class={"competency-chart-group"}>
+                    <h3>{area.to_string()}</h3>
+                    <ul class={"competency-chart"}>{
+                        skills.into_iter().map(|skill| html!{
+                            <li key={skill.id()} class={"competency-chart-row"}>
+                                <span class={"competency-chart-label"}>{skill.name.clone()}</span>
+                                <span
+                                    class={"competency-chart-bar"}
+                                    style={format!("width: {}%;", skill.competency.percentage())}
+                                    title={messages.competency_name(skill.competency)}
+                                ></span>
+                                <span class={"competency-chart-value"}>
+                                    {messages.competency_name(skill.competency)}
+                                </span>
+                            </li>
+                        }).collect::<Html>()
+                    }</ul>
+                </section>
+            }).collect::<Html>()}
+        </section>
+    }
+}
+
+// ********** Achievements ********** //
+// ********************************** //
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Achievement {
+    pub name: String,
+    pub completed: KiraDate,
+    pub areas: Vec<Rc<Area>>,
+    pub tools: Vec<Rc<Tool>>,
+    pub skills: Vec<Rc<Skill>>,
+    pub description: Localised,
+    pub media: Vec<Media>,
+}
+
+impl ContentItem for Achievement {
+    const LIST: &'static str = "achievements";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn date(&self) -> KiraDate {
+        self.completed
+    }
+
+    fn dates(&self) -> Vec<(&'static str, KiraDate)> {
+        vec![("completed", self.completed)]
+    }
+
+    fn texts(&self) -> Vec<(&'static str, Localised)> {
+        vec![
+            ("name", Localised::from(self.name.as_str())),
+            ("description", self.description.clone()),
+        ]
+    }
+
+    fn areas(&self) -> Vec<Area> {
+        self.areas.iter().map(|area| **area).collect()
+    }
+
+    fn tools(&self) -> Vec<Tool> {
+        self.tools.iter().map(|tool| **tool).collect()
+    }
+
+    fn skills(&self) -> &[Rc<Skill>] {
+        &self.skills
+    }
+
+    fn media(&self) -> &[Media] {
+        &self.media
+    }
+
+    fn card_to_html(&self, level: u8) -> Html {
+        html! {
+            <article key={self.id()} class={"card"}>
+                {heading(level, classes!("card-title"), html! {self.name.clone()})}
+                if level > 1 {
+                    {thumbnail(self.media())}
+                }
+                <p class={"card-detail"}>{
+                    self.areas.iter().map(|area| area.to_html()).collect::<Html>()
+                }</p>
+                <p class={"card-detail"}><FormattedDate date={self.completed} style={DateStyle::Long} /></p>
+                <p class={"card-text"}>{self.description.to_string()}</p>
+                <p class={"card-detail"}>{
+                    self.tools.iter().map(|tool| html!{
+                        <span>{tool.to_string() + ". "}</span>
+                    }).collect::<Html>()
+                }</p>
+                <p class={"card-detail"}>{
+                    self.skills.iter().map(|skill| html!{
+                        <span>{skill.name.clone() + ". "}</span>
+                    }).collect::<Html>()
+                }</p>
+            </article>
+        }
+    }
+}
+
+impl ToHtml for Achievement {
+    fn to_html(&self) -> Html {
+        self.card_to_html(2)
+    }
 }
 
+#[derive(Properties, PartialEq, Eq)]
+pub struct AchievementProperties {
+    pub achievement: Achievement,
+}
+
+/// A list of [`Achievement`]s.
+pub type AchievementList = ContentList<Achievement>;
+
 #[derive(Properties, PartialEq, Eq)]
 pub struct AchievementListProperties {
     pub achievements: AchievementList,
@@ -458,27 +1915,65 @@ pub struct AchievementListProperties {
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Creation {
-    pub id: usize,
     pub name: String,
     pub completed: KiraDate,
     pub areas: Vec<Rc<Area>>,
     pub tools: Vec<Rc<Tool>>,
     pub skills: Vec<Rc<Skill>>,
-    pub description: String,
+    pub description: Localised,
+    pub media: Vec<Media>,
 }
 
-impl ToHtml for Creation {
-    fn to_html(&self) -> Html {
+impl ContentItem for Creation {
+    const LIST: &'static str = "creations";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn date(&self) -> KiraDate {
+        self.completed
+    }
+
+    fn dates(&self) -> Vec<(&'static str, KiraDate)> {
+        vec![("completed", self.completed)]
+    }
+
+    fn texts(&self) -> Vec<(&'static str, Localised)> {
+        vec![
+            ("name", Localised::from(self.name.as_str())),
+            ("description", self.description.clone()),
+        ]
+    }
+
+    fn areas(&self) -> Vec<Area> {
+        self.areas.iter().map(|area| **area).collect()
+    }
+
+    fn tools(&self) -> Vec<Tool> {
+        self.tools.iter().map(|tool| **tool).collect()
+    }
+
+    fn skills(&self) -> &[Rc<Skill>] {
+        &self.skills
+    }
+
+    fn media(&self) -> &[Media] {
+        &self.media
+    }
+
+    fn card_to_html(&self, level: u8) -> Html {
         html! {
-            <div key={self.id} class={"card"}>
-                <h3 class={"card-title"}>{self.name.clone()}</h3>
+            <article key={self.id()} class={"card"}>
+                {heading(level, classes!("card-title"), html! {self.name.clone()})}
+                if level > 1 {
+                    {thumbnail(self.media())}
+                }
                 <p class={"card-detail"}>{
-                    self.areas.iter().map(|area| html!{
-                        <span>{area.to_string() + ". "}</span>
-                    }).collect::<Html>()
+                    self.areas.iter().map(|area| area.to_html()).collect::<Html>()
                 }</p>
-                <p class={"card-detail"}>{self.completed.to_string()}</p>
-                <p class={"card-text"}>{self.description.clone()}</p>
+                <p class={"card-detail"}><FormattedDate date={self.completed} style={DateStyle::Long} /></p>
+                <p class={"card-text"}>{self.description.to_string()}</p>
                 <p class={"card-detail"}>{
                     self.tools.iter().map(|tool| html!{
                         <span>{tool.to_string() + "."}</span>
@@ -489,57 +1984,267 @@ impl ToHtml for Creation {
                         <span>{skill.name.clone() + ". "}</span>
                     }).collect::<Html>()
                 }</p>
-            </div>
+            </article>
         }
     }
 }
 
+impl ToHtml for Creation {
+    fn to_html(&self) -> Html {
+        self.card_to_html(2)
+    }
+}
+
 #[derive(Properties, PartialEq, Eq)]
 pub struct CreationProperties {
     pub creation: Creation,
 }
 
-#[derive(Clone, PartialEq, Eq)]
-pub struct CreationList {
-    creations: Vec<Creation>,
+/// A list of [`Creation`]s.
+pub type CreationList = ContentList<Creation>;
+
+#[derive(Properties, PartialEq, Eq)]
+pub struct CreationListProperties {
+    pub creations: CreationList,
+}
+
+// ************ Projects ************ //
+// ********************************** //
+
+/// Whether a [`Project`] is still being worked on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectStatus {
+    Active,
+    Archived,
 }
 
-impl CreationList {
-    pub fn new(creations: Vec<Creation>) -> Self {
-        Self { creations }
+impl Display for ProjectStatus {
+    /// Writes the status's name in the current locale.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Locale::current().messages().project_status(*self))
     }
 }
 
-impl Index<usize> for CreationList {
-    type Output = Creation;
+/// A programming language's share of a [`Project`]'s code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageShare {
+    pub language: String,
+    /// The share of the code, as a percentage.
+    pub percentage: u8,
+}
 
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.creations[index]
+/// A released version of a [`Project`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Release {
+    pub version: String,
+    pub date: KiraDate,
+    pub notes: Localised,
+}
+
+/// A piece of software, with where to find it and how it's progressed. Other
+/// things made are [`Creation`]s.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Project {
+    pub name: String,
+    pub started: KiraDate,
+    pub status: ProjectStatus,
+    pub repository: Option<String>,
+    pub demo: Option<String>,
+    /// The licence's [SPDX identifier](https://spdx.org/licenses/), like
+    /// `"GPL-3.0-or-later"`.
+    pub licence: Option<String>,
+    /// The languages the code is written in, largest share first.
+    pub languages: Vec<LanguageShare>,
+    /// Screenshots and recordings of the project.
+    pub media: Vec<Media>,
+    /// Released versions, oldest first.
+    pub releases: Vec<Release>,
+    pub areas: Vec<Rc<Area>>,
+    pub tools: Vec<Rc<Tool>>,
+    pub skills: Vec<Rc<Skill>>,
+    pub description: Localised,
+}
+
+impl Project {
+    /// Returns the most recent release, or `None` if there hasn't been one.
+    pub fn latest_release(&self) -> Option<&Release> {
+        self.releases.iter().max_by_key(|release| release.date)
+    }
+
+    /// Returns the project's status, licence and latest version as badges.
+    fn badges_to_html(&self) -> Html {
+        let status = match self.status {
+            ProjectStatus::Active => "badge-active",
+            ProjectStatus::Archived => "badge-archived",
+        };
+
+        html! {
+            <p class={"card-detail badges"}>
+                <span class={classes!("badge", status)}>{self.status.to_string()}</span>
+                {self.licence.iter().map(|licence| html!{
+                    <span class={"badge"}>{licence.clone()}</span>
+                }).collect::<Html>()}
+                {self.latest_release().map(|release| html!{
+                    <span class={"badge"}>{release.version.clone()}</span>
+                }).unwrap_or_default()}
+                {self.languages.iter().map(|share| html!{
+                    <span class={"badge badge-language"}>
+                        {format!("{} {}%", share.language, share.percentage)}
+                    </span>
+                }).collect::<Html>()}
+            </p>
+        }
+    }
+
+    /// Returns links to the project's repository and demo, if it has them.
+    fn links_to_html(&self) -> Html {
+        let messages = Locale::current().messages();
+        let links = [
+            (&self.repository, messages.repository),
+            (&self.demo, messages.live_demo),
+        ];
+
+        html! {
+            <p class={"card-detail project-links"}>{
+                links.into_iter().filter_map(|(url, text)| url.as_ref().map(|url| html!{
+                    <a class={"project-link"} href={url.clone()} target={"_blank"} rel={"noopener noreferrer"}>
+                        {text}
+                    </a>
+                })).collect::<Html>()
+            }</p>
+        }
+    }
+
+    /// Returns an [`Html`] history of the project's releases, newest first.
+    pub fn releases_to_html(&self) -> Html {
+        let messages = Locale::current().messages();
+        let mut releases = self.releases.iter().collect::<Vec<&Release>>();
+        releases.sort_by_key(|release| std::cmp::Reverse(release.date));
+
+        html! {
+            <div class={"project-releases"}>
+                <h2>{messages.releases}</h2>
+                if releases.is_empty() {
+                    <p class={"card-detail"}>{messages.no_releases}</p>
+                } else {
+                    <ol>{
+                        releases.into_iter().map(|release| html!{
+                            <li key={release.version.clone()}>
+                                <b>{release.version.clone()}</b>
+                                {" ("}<FormattedDate date={release.date} />{") "}
+                                {release.notes.to_string()}
+                            </li>
+                        }).collect::<Html>()
+                    }</ol>
+                }
+            </div>
+        }
     }
 }
 
-impl Get<&str, Creation> for CreationList {
-    fn get(&self, name: &str) -> Option<Creation> {
-        self.creations
+impl ContentItem for Project {
+    const LIST: &'static str = "projects";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn date(&self) -> KiraDate {
+        self.started
+    }
+
+    fn dates(&self) -> Vec<(&'static str, KiraDate)> {
+        std::iter::once(("started", self.started))
+            .chain(
+                self.releases
+                    .iter()
+                    .map(|release| ("release", release.date)),
+            )
+            .collect()
+    }
+
+    fn texts(&self) -> Vec<(&'static str, Localised)> {
+        let mut texts = vec![
+            ("name", Localised::from(self.name.as_str())),
+            ("description", self.description.clone()),
+        ];
+        for release in &self.releases {
+            texts.push(("release version", Localised::from(release.version.as_str())));
+            texts.push(("release notes", release.notes.clone()));
+        }
+        texts
+    }
+
+    /// Returns the addresses of the project's media and its releases'
+    /// versions.
+    fn keys(&self) -> Vec<(&'static str, String)> {
+        self.media
             .iter()
-            .find(|creation| creation.name == name)
-            .cloned()
+            .map(|media| ("media", media.src.clone()))
+            .chain(
+                self.releases
+                    .iter()
+                    .map(|release| ("release version", release.version.clone())),
+            )
+            .collect()
     }
-}
 
-impl ToHtml for CreationList {
-    fn to_html(&self) -> Html {
+    fn areas(&self) -> Vec<Area> {
+        self.areas.iter().map(|area| **area).collect()
+    }
+
+    fn tools(&self) -> Vec<Tool> {
+        self.tools.iter().map(|tool| **tool).collect()
+    }
+
+    fn skills(&self) -> &[Rc<Skill>] {
+        &self.skills
+    }
+
+    fn media(&self) -> &[Media] {
+        &self.media
+    }
+
+    fn card_to_html(&self, level: u8) -> Html {
         html! {
-            <div class={"content-list"}>
-                {self.creations.iter().map(ToHtml::to_html).collect::<Html>()}
-            </div>
+            <article key={self.id()} class={"card"}>
+                {heading(level, classes!("card-title"), html! {self.name.clone()})}
+                if level > 1 {
+                    {thumbnail(self.media())}
+                }
+                {self.badges_to_html()}
+                <p class={"card-detail"}>{
+                    self.areas.iter().map(|area| area.to_html()).collect::<Html>()
+                }</p>
+                <p class={"card-text"}>{self.description.to_string()}</p>
+                {self.links_to_html()}
+                <p class={"card-detail"}>{
+                    self.tools.iter().map(|tool| html!{
+                        <span>{tool.to_string() + ". "}</span>
+                    }).collect::<Html>()
+                }</p>
+                <p class={"card-detail"}>{
+                    self.skills.iter().map(|skill| html!{
+                        <span>{skill.name.clone() + ". "}</span>
+                    }).collect::<Html>()
+                }</p>
+            </article>
         }
     }
 }
 
+impl ToHtml for Project {
+    fn to_html(&self) -> Html {
+        self.card_to_html(2)
+    }
+}
+
+/// A list of [`Project`]s.
+pub type ProjectList = ContentList<Project>;
+
 #[derive(Properties, PartialEq, Eq)]
-pub struct CreationListProperties {
-    pub creations: CreationList,
+pub struct ProjectListProperties {
+    pub projects: ProjectList,
 }
 
 // ************ Articles ************ //
@@ -547,76 +2252,283 @@ pub struct CreationListProperties {
 
 #[derive(Clone, PartialEq)]
 pub struct Article {
-    pub id: usize,
     pub title: String,
     pub published: KiraDate,
     pub topics: Vec<Rc<Topic>>,
-    pub summary: String,
+    pub summary: Localised,
     pub content: Html,
 }
 
-impl ToHtml for Article {
-    fn to_html(&self) -> Html {
+impl ContentItem for Article {
+    const LIST: &'static str = "articles";
+
+    fn name(&self) -> &str {
+        &self.title
+    }
+
+    fn date(&self) -> KiraDate {
+        self.published
+    }
+
+    fn dates(&self) -> Vec<(&'static str, KiraDate)> {
+        vec![("published", self.published)]
+    }
+
+    /// Returns the article's title, summary and the topics that aren't areas
+    /// or tools; its content is markup rather than text, so isn't included.
+    fn texts(&self) -> Vec<(&'static str, Localised)> {
+        let mut texts = vec![
+            ("title", Localised::from(self.title.as_str())),
+            ("summary", self.summary.clone()),
+        ];
+        texts.extend(self.topics.iter().filter_map(|topic| match topic.as_ref() {
+            Topic::Other(other) => Some(("topic", Localised::from(*other))),
+            _ => None,
+        }));
+        texts
+    }
+
+    /// Returns the areas among the article's topics.
+    fn areas(&self) -> Vec<Area> {
+        self.topics
+            .iter()
+            .filter_map(|topic| match topic.as_ref() {
+                Topic::Area(area) => Some(**area),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the tools among the article's topics.
+    fn tools(&self) -> Vec<Tool> {
+        self.topics
+            .iter()
+            .filter_map(|topic| match topic.as_ref() {
+                Topic::Tool(tool) => Some(**tool),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn card_to_html(&self, level: u8) -> Html {
         html! {
-            <article key={self.id} class={"card"}>
-                <h3 class={"card-title"}>{self.title.clone()}</h3>
-                <p class={"card-detail"}>{self.published.to_string()}</p>
+            <article key={self.id()} class={"card"}>
+                {heading(level, classes!("card-title"), html! {self.title.clone()})}
+                <p class={"card-detail"}><FormattedDate date={self.published} style={DateStyle::Long} /></p>
                 <p class={"card-detail"}>{
                     self.topics.iter().map(|topic| html!{
                         <span>{topic.to_string() + ". "}</span>
                     }).collect::<Html>()
                 }</p>
-                <p class={"card-text"}>{self.summary.clone()}</p>
+                <p class={"card-text"}>{self.summary.to_string()}</p>
                 <div class={"card-content"}>{self.content.clone()}</div>
             </article>
         }
     }
 }
 
+impl ToHtml for Article {
+    fn to_html(&self) -> Html {
+        self.card_to_html(2)
+    }
+}
+
 #[derive(Properties, PartialEq)]
 pub struct ArticleProperties {
     pub article: Article,
 }
 
-#[derive(Clone, PartialEq)]
-pub struct ArticleList {
-    articles: Vec<Article>,
+/// A list of [`Article`]s.
+pub type ArticleList = ContentList<Article>;
+
+#[derive(Properties, PartialEq)]
+pub struct ArticleListProperties {
+    pub articles: ArticleList,
 }
 
-impl ArticleList {
-    pub fn new(articles: Vec<Article>) -> Self {
-        Self { articles }
-    }
+// *********** Education  *********** //
+// ********************************** //
+
+/// A course of study, like a degree or other qualification.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Education {
+    /// The qualification studied for, which is the education's name.
+    pub qualification: String,
+    pub institution: String,
+    pub started: KiraDate,
+    /// When the course was finished, or `None` if it is still being studied.
+    pub finished: Option<KiraDate>,
+    pub areas: Vec<Rc<Area>>,
+    pub skills: Vec<Rc<Skill>>,
+    pub description: Localised,
 }
 
-impl Index<usize> for ArticleList {
-    type Output = Article;
+impl ContentItem for Education {
+    const LIST: &'static str = "education";
 
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.articles[index]
+    fn name(&self) -> &str {
+        &self.qualification
     }
-}
 
-impl Get<&str, Article> for ArticleList {
-    fn get(&self, title: &str) -> Option<Article> {
-        self.articles
-            .iter()
-            .find(|article| article.title == title)
-            .cloned()
+    fn date(&self) -> KiraDate {
+        self.started
+    }
+
+    fn dates(&self) -> Vec<(&'static str, KiraDate)> {
+        std::iter::once(("started", self.started))
+            .chain(self.finished.map(|date| ("finished", date)))
+            .collect()
+    }
+
+    fn texts(&self) -> Vec<(&'static str, Localised)> {
+        vec![
+            (
+                "qualification",
+                Localised::from(self.qualification.as_str()),
+            ),
+            ("institution", Localised::from(self.institution.as_str())),
+            ("description", self.description.clone()),
+        ]
+    }
+
+    fn areas(&self) -> Vec<Area> {
+        self.areas.iter().map(|area| **area).collect()
+    }
+
+    fn skills(&self) -> &[Rc<Skill>] {
+        &self.skills
+    }
+
+    fn card_to_html(&self, level: u8) -> Html {
+        html! {
+            <article key={self.id()} class={"card"}>
+                {heading(level, classes!("card-title"), html! {self.qualification.clone()})}
+                <p class={"card-detail"}>{self.institution.clone()}</p>
+                <p class={"card-detail"}>{date_range(self.started, self.finished)}</p>
+                <p class={"card-detail"}>{
+                    self.areas.iter().map(|area| area.to_html()).collect::<Html>()
+                }</p>
+                <p class={"card-text"}>{self.description.to_string()}</p>
+                <p class={"card-detail"}>{
+                    self.skills.iter().map(|skill| html!{
+                        <span>{skill.name.clone() + ". "}</span>
+                    }).collect::<Html>()
+                }</p>
+            </article>
+        }
     }
 }
 
-impl ToHtml for ArticleList {
+impl ToHtml for Education {
     fn to_html(&self) -> Html {
+        self.card_to_html(2)
+    }
+}
+
+/// A list of [`Education`].
+pub type EducationList = ContentList<Education>;
+
+#[derive(Properties, PartialEq, Eq)]
+pub struct EducationListProperties {
+    pub education: EducationList,
+}
+
+// *********** Experience *********** //
+// ********************************** //
+
+/// The kinds of [`Experience`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExperienceKind {
+    Work,
+    Volunteering,
+}
+
+impl Display for ExperienceKind {
+    /// Writes the kind's name in the current locale.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Locale::current().messages().experience_kind(*self))
+    }
+}
+
+/// A role held at an organisation, paid or not.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Experience {
+    /// The role, which is the experience's name, so must be unique; add the
+    /// organisation to roles held at more than one, like "Volunteer (Oxfam)".
+    pub role: String,
+    pub organisation: String,
+    pub kind: ExperienceKind,
+    pub started: KiraDate,
+    /// When the role ended, or `None` if it is still held.
+    pub ended: Option<KiraDate>,
+    pub areas: Vec<Rc<Area>>,
+    pub skills: Vec<Rc<Skill>>,
+    pub description: Localised,
+}
+
+impl ContentItem for Experience {
+    const LIST: &'static str = "experience";
+
+    fn name(&self) -> &str {
+        &self.role
+    }
+
+    fn date(&self) -> KiraDate {
+        self.started
+    }
+
+    fn dates(&self) -> Vec<(&'static str, KiraDate)> {
+        std::iter::once(("started", self.started))
+            .chain(self.ended.map(|date| ("ended", date)))
+            .collect()
+    }
+
+    fn texts(&self) -> Vec<(&'static str, Localised)> {
+        vec![
+            ("role", Localised::from(self.role.as_str())),
+            ("organisation", Localised::from(self.organisation.as_str())),
+            ("description", self.description.clone()),
+        ]
+    }
+
+    fn areas(&self) -> Vec<Area> {
+        self.areas.iter().map(|area| **area).collect()
+    }
+
+    fn skills(&self) -> &[Rc<Skill>] {
+        &self.skills
+    }
+
+    fn card_to_html(&self, level: u8) -> Html {
         html! {
-            <div class={"content-list"}>
-                {self.articles.iter().map(ToHtml::to_html).collect::<Html>()}
-            </div>
+            <article key={self.id()} class={"card"}>
+                {heading(level, classes!("card-title"), html! {self.role.clone()})}
+                <p class={"card-detail"}>{format!("{} ({})", self.organisation, self.kind)}</p>
+                <p class={"card-detail"}>{date_range(self.started, self.ended)}</p>
+                <p class={"card-detail"}>{
+                    self.areas.iter().map(|area| area.to_html()).collect::<Html>()
+                }</p>
+                <p class={"card-text"}>{self.description.to_string()}</p>
+                <p class={"card-detail"}>{
+                    self.skills.iter().map(|skill| html!{
+                        <span>{skill.name.clone() + ". "}</span>
+                    }).collect::<Html>()
+                }</p>
+            </article>
         }
     }
 }
 
-#[derive(Properties, PartialEq)]
-pub struct ArticleListProperties {
-    pub articles: ArticleList,
+impl ToHtml for Experience {
+    fn to_html(&self) -> Html {
+        self.card_to_html(2)
+    }
+}
+
+/// A list of [`Experience`].
+pub type ExperienceList = ContentList<Experience>;
+
+#[derive(Properties, PartialEq, Eq)]
+pub struct ExperienceListProperties {
+    pub experience: ExperienceList,
 }
@@ -0,0 +1,316 @@
+use crate::{
+    AchievementList, Area, AreaList, ArticleList, Competency, ContactBackend, ContactDetails,
+    ContactLink, ContentError, ContentItem, Creation, CreationList, EducationList, ExperienceList,
+    Get, HttpBackend, KiraDate, Localised, MailtoBackend, Media, Project, ProjectList,
+    ProjectStatus, Route, Skill, SkillList, SkillUsages, Tool, ToolList, EMAIL, GITHUB, INSTAGRAM,
+    PINTEREST, REDDIT, TUMBLR,
+};
+use std::rc::Rc;
+
+/// The endpoint the contact form posts messages to, or `None` to fall back to
+/// opening the visitor's email app.
+pub const CONTACT_ENDPOINT: Option<&str> = None;
+
+/// A language tag, like `en-US`, whose date conventions every visitor sees, or
+/// `None` to follow each visitor's browser.
+pub const DATE_LOCALE: Option<&str> = None;
+
+/// Returns the backend the contact form sends messages with.
+pub fn contact_backend(details: &ContactDetails) -> Rc<dyn ContactBackend> {
+    match CONTACT_ENDPOINT {
+        Some(endpoint) => Rc::new(HttpBackend::new(endpoint)),
+        None => Rc::new(MailtoBackend::new(details.email().unwrap_or_default())),
+    }
+}
+
+/// Returns the site owner's contact details.
+pub fn contact_details() -> ContactDetails {
+    ContactDetails {
+        given_name: "Kira",
+        family_name: "Hudson",
+        url: "https://queenkirathepansexualpixie.github.io/",
+        links: vec![
+            ContactLink {
+                platform: EMAIL,
+                handle: "kira.hudson.v0@gmail.com",
+            },
+            ContactLink {
+                platform: GITHUB,
+                handle: "QueenKiraThePansexualPixie",
+            },
+            ContactLink {
+                platform: TUMBLR,
+                handle: "kira-is-pan",
+            },
+            ContactLink {
+                platform: INSTAGRAM,
+                handle: "kirathepanpixie",
+            },
+            ContactLink {
+                platform: PINTEREST,
+                handle: "kirathepansexualpixie",
+            },
+            ContactLink {
+                platform: REDDIT,
+                handle: "KiraThePanPixie",
+            },
+        ],
+    }
+}
+
+/// All of the site's content, built once and shared by the app and the
+/// native tooling.
+#[derive(Clone, PartialEq)]
+pub struct Portfolio {
+    pub contact: ContactDetails,
+    pub areas: AreaList,
+    pub tools: ToolList,
+    pub skills: SkillList,
+    pub achievements: AchievementList,
+    pub creations: CreationList,
+    pub projects: ProjectList,
+    pub articles: ArticleList,
+    pub education: EducationList,
+    pub experience: ExperienceList,
+    pub usage: SkillUsages,
+}
+
+impl Portfolio {
+    /// Returns the display name of the content item a route points to, or
+    /// `None` if the route isn't for a content item or the item doesn't exist.
+    pub fn title_of(&self, route: &Route) -> Option<String> {
+        match route {
+            Route::Skill { skill } => self.skills.get(skill).map(|skill| skill.name),
+            Route::Achievement { achievement } => self
+                .achievements
+                .get(achievement)
+                .map(|achievement| achievement.name),
+            Route::Creation { creation } => {
+                self.creations.get(creation).map(|creation| creation.name)
+            }
+            Route::Project { project } => self.projects.get(project).map(|project| project.name),
+            Route::Article { article } => self.articles.get(article).map(|article| article.title),
+            Route::Qualification { qualification } => self
+                .education
+                .get(qualification)
+                .map(|education| education.qualification),
+            Route::Role { role } => self.experience.get(role).map(|experience| experience.role),
+            Route::Area { area } => self
+                .areas
+                .get(area)
+                .map(|area| area.short_name().to_string()),
+            _ => None,
+        }
+    }
+
+    /// Returns the media attached to every content item, in list order.
+    pub fn media(&self) -> impl Iterator<Item = &Media> {
+        self.achievements
+            .iter()
+            .flat_map(ContentItem::media)
+            .chain(self.creations.iter().flat_map(ContentItem::media))
+            .chain(self.projects.iter().flat_map(ContentItem::media))
+    }
+
+    /// Builds every content list, including the reverse index of skill usage.
+    ///
+    /// # Errors
+    ///
+    /// Fails with every broken piece of content, like a reference to an area
+    /// that isn't in the area list or two items with the same name, so that
+    /// they're caught by the tests and `kira-content check` rather than shown
+    /// to visitors.
+    pub fn load() -> Result<Self, Vec<ContentError>> {
+        let (portfolio, mut errors) = Self::load_unchecked();
+
+        errors.extend(portfolio.areas.errors());
+        errors.extend(portfolio.tools.errors());
+        errors.extend(portfolio.skills.errors());
+        errors.extend(portfolio.achievements.errors());
+        errors.extend(portfolio.creations.errors());
+        errors.extend(portfolio.projects.errors());
+        errors.extend(portfolio.articles.errors());
+        errors.extend(portfolio.education.errors());
+        errors.extend(portfolio.experience.errors());
+
+        if errors.is_empty() {
+            Ok(portfolio)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Builds every content list without validating them, along with every
+    /// broken reference found on the way, each replaced by its "not found"
+    /// value so that `kira-content check` can check the rest of the content.
+    #[allow(clippy::too_many_lines)]
+    pub fn load_unchecked() -> (Self, Vec<ContentError>) {
+        let mut errors = Vec::new();
+
+        let areas: AreaList = AreaList::from(vec![
+            Area("development"),
+            Area("development/backend"),
+            Area("development/database"),
+            Area("development/frontend"),
+            Area("development/game"),
+            Area("development/systems"),
+            Area("development/web"),
+            Area("graphics/digital"),
+            Area("language"),
+            Area("scripting"),
+        ]);
+        let tools: ToolList = ToolList::from(vec![
+            Tool("Blender"),
+            Tool("Illustrator"),
+            Tool("Photoshop"),
+            Tool("Unity"),
+            Tool("Visual Studio"),
+            Tool("Visual Studio Code"),
+        ]);
+
+        let skills: SkillList = SkillList::new(vec![
+            Skill {
+                name: "Rust".to_string(),
+                areas: vec![
+                    Rc::new(areas.get_or_record("development/backend", "skills", "Rust", &mut errors)),
+                    Rc::new(areas.get_or_record("development/frontend", "skills", "Rust", &mut errors)),
+                    Rc::new(areas.get_or_record("development/game", "skills", "Rust", &mut errors)),
+                    Rc::new(areas.get_or_record("development/systems", "skills", "Rust", &mut errors)),
+                    Rc::new(areas.get_or_record("development/web", "skills", "Rust", &mut errors)),
+                    Rc::new(areas.get_or_record("scripting", "skills", "Rust", &mut errors)),
+                ],
+                competency: Competency::Intermediate,
+                history: vec![],
+                category: Some("Programming Languages".to_string()),
+                started: None,
+                last_used: None,
+                description: Localised::from("A high-level systems programming language, designed for interacting more safely with low-level concepts."),
+            },
+            Skill {
+                name: "Python".to_string(),
+                areas: vec![
+                    Rc::new(areas.get_or_record("development/backend", "skills", "Python", &mut errors)),
+                    Rc::new(areas.get_or_record("development/frontend", "skills", "Python", &mut errors)),
+                    Rc::new(areas.get_or_record("development/game", "skills", "Python", &mut errors)),
+                    Rc::new(areas.get_or_record("development/web", "skills", "Python", &mut errors)),
+                    Rc::new(areas.get_or_record("scripting", "skills", "Python", &mut errors)),
+                ],
+                competency: Competency::Intermediate,
+                history: vec![],
+                category: Some("Programming Languages".to_string()),
+                started: None,
+                last_used: None,
+                description: Localised::from("A high-level, interpreted programming language with a focus on readbility and fast launch-times over fast and powerful programs."),
+            },
+            Skill {
+                name: "C++".to_string(),
+                areas: vec![
+                    Rc::new(areas.get_or_record("development/backend", "skills", "C++", &mut errors)),
+                    Rc::new(areas.get_or_record("development/database", "skills", "C++", &mut errors)),
+                    Rc::new(areas.get_or_record("development/frontend", "skills", "C++", &mut errors)),
+                    Rc::new(areas.get_or_record("development/game", "skills", "C++", &mut errors)),
+                    Rc::new(areas.get_or_record("development/systems", "skills", "C++", &mut errors)),
+                    Rc::new(areas.get_or_record("development/web", "skills", "C++", &mut errors)),
+                    Rc::new(areas.get_or_record("graphics/digital", "skills", "C++", &mut errors)),
+                    Rc::new(areas.get_or_record("scripting", "skills", "C++", &mut errors)),
+                ],
+                competency: Competency::Novice,
+                history: vec![],
+                category: Some("Programming Languages".to_string()),
+                started: None,
+                last_used: None,
+                description: Localised::from("A low-level, high-control, systems programming language."),
+            },
+            Skill {
+                name: "Web Development".to_string(),
+                areas: vec![
+                    Rc::new(areas.get_or_record("development/backend", "skills", "Web Development", &mut errors)),
+                    Rc::new(areas.get_or_record("development/frontend", "skills", "Web Development", &mut errors)),
+                    Rc::new(areas.get_or_record("development/web", "skills", "Web Development", &mut errors)),
+                ],
+                competency: Competency::Intermediate,
+                history: vec![],
+                category: Some("Disciplines".to_string()),
+                started: None,
+                last_used: None,
+                description: Localised::from("Web development."),
+            },
+        ]);
+
+        let achievements: AchievementList = AchievementList::new(vec![]);
+
+        let creations: CreationList = CreationList::new(vec![Creation {
+            name: "Web Profile".to_string(),
+            completed: KiraDate::new(2023, 9, 16),
+            areas: vec![Rc::new(areas.get_or_record(
+                "development/web",
+                "creations",
+                "Web Profile",
+                &mut errors,
+            ))],
+            tools: vec![Rc::new(tools.get_or_record(
+                "Visual Studio Code",
+                "creations",
+                "Web Profile",
+                &mut errors,
+            ))],
+            skills: vec![Rc::new(skills.get_or_record(
+                "Web Development",
+                "creations",
+                "Web Profile",
+                &mut errors,
+            ))],
+            description: Localised::from("A personal profile website."),
+            media: vec![],
+        }]);
+
+        let projects: ProjectList = ProjectList::new(vec![Project {
+            name: "Web Profile".to_string(),
+            started: KiraDate::new(2023, 9, 16),
+            status: ProjectStatus::Active,
+            repository: Some(
+                "https://github.com/QueenKiraThePansexualPixie/queenkirathepansexualpixie.github.io"
+                    .to_string(),
+            ),
+            demo: Some("https://queenkirathepansexualpixie.github.io/".to_string()),
+            licence: Some("GPL-3.0-or-later".to_string()),
+            languages: vec![],
+            media: vec![],
+            releases: vec![],
+            areas: vec![Rc::new(areas.get_or_record("development/web", "projects", "Web Profile", &mut errors))],
+            tools: vec![Rc::new(tools.get_or_record("Visual Studio Code", "projects", "Web Profile", &mut errors))],
+            skills: vec![Rc::new(skills.get_or_record("Web Development", "projects", "Web Profile", &mut errors))],
+            description: Localised::from("A personal profile website."),
+        }]);
+
+        let articles: ArticleList = ArticleList::new(vec![]);
+
+        let education: EducationList = EducationList::new(vec![]);
+
+        let experience: ExperienceList = ExperienceList::new(vec![]);
+
+        let usage = SkillUsages::new(
+            &achievements,
+            &creations,
+            &projects,
+            &education,
+            &experience,
+        );
+
+        let portfolio = Self {
+            contact: contact_details(),
+            areas,
+            tools,
+            skills,
+            achievements,
+            creations,
+            projects,
+            articles,
+            education,
+            experience,
+            usage,
+        };
+
+        (portfolio, errors)
+    }
+}
@@ -0,0 +1,176 @@
+//! Display of [`KiraDate`]s in the visitor's conventions.
+//!
+//! Month names come from the site's [`Locale`]. The order of the day, month and
+//! year comes from [`DATE_LOCALE`] if it is set, or else from the browser's
+//! language, so that a visitor using `en-US` sees "August 17, 2023" while one
+//! using `en-GB` sees "17 August 2023".
+//!
+//! Feeds, structured data and other machine-readable outputs don't use any of
+//! this, and always write dates with [`KiraDate::to_iso_string`].
+
+use crate::{data::DATE_LOCALE, DateOrder, KiraDate, Locale};
+use std::cell::Cell;
+use yew::prelude::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Locale::English, "en-GB", DateOrder::DayMonthYear)]
+    #[case(Locale::English, "en-US", DateOrder::MonthDayYear)]
+    #[case(Locale::English, "en_us", DateOrder::MonthDayYear)]
+    #[case(Locale::English, "en", DateOrder::DayMonthYear)]
+    #[case(Locale::French, "fr-CA", DateOrder::DayMonthYear)]
+    // The conventions of another language don't apply to this one's text.
+    #[case(Locale::French, "en-US", DateOrder::DayMonthYear)]
+    fn date_format_for_tag_works_as_expected(
+        #[case] locale: Locale,
+        #[case] tag: &str,
+        #[case] expected: DateOrder,
+    ) {
+        assert_eq!(DateFormat::for_tag(locale, tag).order, expected);
+    }
+
+    #[rstest]
+    #[case(DateStyle::Long, DateOrder::DayMonthYear, "7 August 2023")]
+    #[case(DateStyle::Medium, DateOrder::DayMonthYear, "7 Aug 2023")]
+    #[case(DateStyle::Short, DateOrder::DayMonthYear, "07/08/2023")]
+    #[case(DateStyle::Long, DateOrder::MonthDayYear, "August 7, 2023")]
+    #[case(DateStyle::Medium, DateOrder::MonthDayYear, "Aug 7, 2023")]
+    #[case(DateStyle::Short, DateOrder::MonthDayYear, "08/07/2023")]
+    #[case(DateStyle::Long, DateOrder::YearMonthDay, "2023 August 7")]
+    #[case(DateStyle::Short, DateOrder::YearMonthDay, "2023-08-07")]
+    fn kira_date_format_works_as_expected(
+        #[case] style: DateStyle,
+        #[case] order: DateOrder,
+        #[case] expected: &str,
+    ) {
+        let date_format = DateFormat {
+            locale: Locale::English,
+            order,
+        };
+
+        assert_eq!(
+            KiraDate::new(2023, 8, 7).format(style, date_format),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn kira_date_format_uses_locale_month_names() {
+        let date_format = DateFormat::for_tag(Locale::French, "fr-FR");
+
+        assert_eq!(
+            KiraDate::new(2023, 2, 1).format(DateStyle::Medium, date_format),
+            "1 févr. 2023"
+        );
+    }
+}
+
+/// Regions that write the month before the day.
+const MONTH_FIRST_REGIONS: [&str; 5] = ["US", "PH", "FM", "MH", "PW"];
+
+thread_local! {
+    static CURRENT: Cell<DateFormat> = Cell::new(DateFormat::from(Locale::default()));
+}
+
+/// How much of a date to write out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateStyle {
+    /// The month's full name, like "17 August 2023".
+    Long,
+    /// The month's abbreviated name, like "17 Aug 2023".
+    Medium,
+    /// Numbers only, like "17/08/2023".
+    Short,
+}
+
+/// The conventions dates are written in: the month names of a locale, and the
+/// order of the parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateFormat {
+    pub locale: Locale,
+    pub order: DateOrder,
+}
+
+impl DateFormat {
+    /// Returns the format for text in `locale`, read by someone whose language
+    /// tag is `tag`, like `en-US`.
+    ///
+    /// The tag's region only changes the order if its language is the
+    /// locale's, as its conventions would be out of place in other languages.
+    pub fn for_tag(locale: Locale, tag: &str) -> Self {
+        let mut subtags = tag.split(['-', '_']);
+        let same_language = subtags
+            .next()
+            .is_some_and(|language| language.eq_ignore_ascii_case(locale.code()));
+        let month_first = subtags.any(|region| {
+            MONTH_FIRST_REGIONS
+                .iter()
+                .any(|month_first| region.eq_ignore_ascii_case(month_first))
+        });
+
+        Self {
+            locale,
+            order: if same_language && month_first {
+                DateOrder::MonthDayYear
+            } else {
+                locale.messages().date_order
+            },
+        }
+    }
+
+    /// Returns the format to show dates to the visitor in, for text in
+    /// `locale`, from [`DATE_LOCALE`] or the browser's language.
+    pub fn detect(locale: Locale) -> Self {
+        let tag = DATE_LOCALE.map(ToString::to_string).or_else(|| {
+            if cfg!(target_arch = "wasm32") {
+                web_sys::window().and_then(|window| window.navigator().language())
+            } else {
+                None
+            }
+        });
+
+        tag.map_or_else(|| Self::from(locale), |tag| Self::for_tag(locale, &tag))
+    }
+
+    /// Returns the format dates are being shown in.
+    pub fn current() -> Self {
+        CURRENT.with(Cell::get)
+    }
+
+    /// Makes the format the one dates are shown in.
+    pub fn set_current(self) {
+        CURRENT.with(|current| current.set(self));
+    }
+}
+
+impl From<Locale> for DateFormat {
+    /// Returns the locale's usual format.
+    fn from(locale: Locale) -> Self {
+        Self {
+            locale,
+            order: locale.messages().date_order,
+        }
+    }
+}
+
+#[derive(Properties, PartialEq, Eq)]
+pub struct FormattedDateProperties {
+    pub date: KiraDate,
+    #[prop_or(DateStyle::Medium)]
+    pub style: DateStyle,
+}
+
+/// A date in the [current](DateFormat::current) format, marked up with its ISO
+/// 8601 form for machines.
+#[function_component(FormattedDate)]
+pub fn formatted_date(FormattedDateProperties { date, style }: &FormattedDateProperties) -> Html {
+    html! {
+        <time datetime={date.to_iso_string()}>
+            {date.format(*style, DateFormat::current())}
+        </time>
+    }
+}
@@ -0,0 +1,102 @@
+//! What the app shows instead of a blank page when something goes wrong.
+//!
+//! If the content fails to load, debug builds show the [`Diagnostics`]
+//! overlay, with what's wrong and how to find out more, and release builds
+//! show visitors the [`ErrorPage`](crate::ErrorPage) instead. If the app
+//! panics, the page is replaced with the error page's message before the
+//! module aborts.
+
+use crate::{ContentError, Locale};
+use yew::prelude::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("plain text", "plain text")]
+    #[case("<script>&</script>", "&lt;script&gt;&amp;&lt;/script&gt;")]
+    fn escape_html_works_as_expected(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(escape_html(text), expected);
+    }
+}
+
+#[derive(Properties, PartialEq, Eq)]
+pub struct DiagnosticsProperties {
+    pub errors: Vec<ContentError>,
+}
+
+/// An overlay describing why the content failed to load, for debug builds.
+#[function_component(Diagnostics)]
+pub fn diagnostics(DiagnosticsProperties { errors }: &DiagnosticsProperties) -> Html {
+    let errors = errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    html! {
+        <main
+            id={"main"}
+            class={"diagnostics"}
+            role={"alertdialog"}
+            aria-modal={"true"}
+            aria-labelledby={"diagnostics-title"}
+            aria-describedby={"diagnostics-error"}
+        >
+            <h1 id={"diagnostics-title"}>{"Content Error"}</h1>
+
+            <pre id={"diagnostics-error"}>{errors}</pre>
+
+            <p>
+                {"Fix it in "}<code>{"src/data.rs"}</code>{", then run "}
+                <code>{"cargo run --bin kira-content check"}</code>
+                {" to find any other problems with the content."}
+            </p>
+
+            <p class={"diagnostics-note"}>
+                {"This overlay is only shown in debug builds. Release builds show visitors an error page instead."}
+            </p>
+        </main>
+    }
+}
+
+/// Makes panics replace the page with the error page's message, as the
+/// release profile's `panic = "abort"` would otherwise leave it blank. Debug
+/// builds add the panic's message and location.
+///
+/// Does nothing outside of the browser.
+pub fn install_panic_hook() {
+    if cfg!(not(target_arch = "wasm32")) {
+        return;
+    }
+
+    std::panic::set_hook(Box::new(|info| {
+        let Some(body) = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.body())
+        else {
+            return;
+        };
+        let messages = Locale::current().messages();
+        let details = if cfg!(debug_assertions) {
+            format!("<pre>{}</pre>", escape_html(&info.to_string()))
+        } else {
+            String::new()
+        };
+
+        body.set_inner_html(&format!(
+            "<main id=\"main\" class=\"error-page\"><h1>{}</h1><p>{}</p>{details}</main>",
+            escape_html(messages.error_title),
+            escape_html(messages.error_detail),
+        ));
+    }));
+}
+
+/// Returns the text with the characters HTML treats specially escaped.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -0,0 +1,535 @@
+//! Translation of the site's text.
+//!
+//! Every piece of UI text lives in a [`Messages`] catalogue, one per
+//! [`Locale`], in the `i18n/` directory. Content that can be translated, like
+//! skill descriptions, is [`Localised`].
+//!
+//! The locale is chosen once, when the app starts, by [`Locale::detect`]. Every
+//! locale other than the default has its own path prefix (e.g. `/fr/skills`),
+//! which the app passes to the router as its basename, so switching locale is
+//! a page load and the rest of the app can read [`Locale::current`] freely.
+
+mod en;
+mod fr;
+
+use crate::{
+    Competency, ContactError, CvSection, ExperienceKind, FieldError, ProjectStatus, Route,
+    SkillOrder, Theme, Year,
+};
+use std::{cell::Cell, fmt::Display};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("/", None)]
+    #[case("/skills", None)]
+    #[case("/fr", Some(Locale::French))]
+    #[case("/fr/skills/Rust", Some(Locale::French))]
+    #[case("/french", None)]
+    fn locale_from_path_works_as_expected(#[case] path: &str, #[case] expected: Option<Locale>) {
+        assert_eq!(Locale::from_path(path), expected);
+    }
+
+    #[rstest]
+    #[case("fr", Some(Locale::French))]
+    #[case("fr-CA", Some(Locale::French))]
+    #[case("EN-us", Some(Locale::English))]
+    #[case("de-DE", None)]
+    fn locale_from_tag_works_as_expected(#[case] tag: &str, #[case] expected: Option<Locale>) {
+        assert_eq!(Locale::from_tag(tag), expected);
+    }
+
+    #[rstest]
+    fn locale_path_to_works_as_expected() {
+        assert_eq!(Locale::English.path_to(&Route::Skills), "/skills");
+        assert_eq!(Locale::French.path_to(&Route::Skills), "/fr/skills");
+        assert_eq!(Locale::French.path_to(&Route::Home), "/fr/");
+    }
+
+    #[rstest]
+    fn localised_falls_back_to_the_default_locale() {
+        let text = Localised::from("Hello").with(Locale::French, "Bonjour");
+
+        assert_eq!(text.get(Locale::French), "Bonjour");
+        assert_eq!(text.get(Locale::English), "Hello");
+        assert_eq!(Localised::from("Hello").get(Locale::French), "Hello");
+    }
+}
+
+/// The `localStorage` key the visitor's chosen locale is saved under.
+pub const LOCALE_STORAGE_KEY: &str = "locale";
+
+thread_local! {
+    static CURRENT: Cell<Locale> = Cell::new(Locale::default());
+}
+
+// ********************************* Locales ******************************** //
+// ************************************************************************** //
+
+/// A language the site is available in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Locale {
+    #[default]
+    English,
+    French,
+}
+
+impl Locale {
+    /// Every locale, in the order they are offered.
+    pub const ALL: [Self; 2] = [Self::English, Self::French];
+
+    /// Returns the locale's BCP 47 language tag, as used in `lang` attributes
+    /// and path prefixes.
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::French => "fr",
+        }
+    }
+
+    /// Returns the locale's name, in its own language.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::French => "Français",
+        }
+    }
+
+    /// Returns the locale's message catalogue.
+    pub const fn messages(self) -> &'static Messages {
+        match self {
+            Self::English => &en::MESSAGES,
+            Self::French => &fr::MESSAGES,
+        }
+    }
+
+    /// Returns the prefix of the locale's paths, or `None` for the default
+    /// locale, whose paths have no prefix.
+    pub fn base_path(self) -> Option<String> {
+        (self != Self::default()).then(|| format!("/{}", self.code()))
+    }
+
+    /// Returns the path to `route` in the locale.
+    pub fn path_to(self, route: &Route) -> String {
+        format!(
+            "{}{}",
+            self.base_path().unwrap_or_default(),
+            route.to_path()
+        )
+    }
+
+    /// Returns the locale matching a language tag like `fr-CA`, by its primary
+    /// language, or `None` if the language isn't supported.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.split(['-', '_']).next()?.to_lowercase();
+
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.code() == language)
+    }
+
+    /// Returns the locale whose prefix `path` starts with, or `None` if it has
+    /// no locale prefix.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let prefix = path.trim_start_matches('/').split('/').next()?;
+
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.base_path().as_deref() == Some(&format!("/{prefix}")))
+    }
+
+    /// Returns the locale the app is being rendered in.
+    pub fn current() -> Self {
+        CURRENT.with(Cell::get)
+    }
+
+    /// Makes the locale the one the app is rendered in, and sets the `lang`
+    /// attribute of the page to match.
+    pub fn set_current(self) {
+        CURRENT.with(|current| current.set(self));
+
+        if cfg!(not(target_arch = "wasm32")) {
+            return;
+        }
+
+        if let Some(root) = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.document_element())
+        {
+            let _ = root.set_attribute("lang", self.code());
+        }
+    }
+
+    /// Returns the locale to render the app in, which is the locale of the
+    /// path prefix if there is one.
+    ///
+    /// Otherwise, this is the locale the visitor last chose, or the first of
+    /// their browser's languages that the site supports. If that isn't the
+    /// default locale, the browser is sent to the same page in that locale.
+    pub fn detect() -> Self {
+        if cfg!(not(target_arch = "wasm32")) {
+            return Self::default();
+        }
+
+        let Some(window) = web_sys::window() else {
+            return Self::default();
+        };
+        let location = window.location();
+        let path = location.pathname().unwrap_or_default();
+
+        if let Some(locale) = Self::from_path(&path) {
+            return locale;
+        }
+
+        let preferred = window
+            .local_storage()
+            .ok()
+            .flatten()
+            .and_then(|storage| storage.get_item(LOCALE_STORAGE_KEY).ok().flatten())
+            .or_else(|| window.navigator().language())
+            .and_then(|tag| Self::from_tag(&tag))
+            .unwrap_or_default();
+
+        if let Some(base_path) = preferred.base_path() {
+            let _ = location.replace(&format!(
+                "{base_path}{path}{}{}",
+                location.search().unwrap_or_default(),
+                location.hash().unwrap_or_default()
+            ));
+        }
+
+        preferred
+    }
+
+    /// Saves the locale as the visitor's choice in `localStorage`.
+    ///
+    /// Failures are ignored, as the choice is still made for this visit.
+    pub fn save(self) {
+        if let Some(storage) =
+            web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+        {
+            let _ = storage.set_item(LOCALE_STORAGE_KEY, self.code());
+        }
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Links to the current page in every locale, saving the visitor's choice.
+#[function_component(LocaleSwitcher)]
+pub fn locale_switcher() -> Html {
+    let route = use_route::<Route>().unwrap_or(Route::Home);
+    let current = Locale::current();
+
+    html! {
+        <nav class={"locale-switcher"} aria-label={current.messages().language}>
+            {Locale::ALL.into_iter().map(|locale| {
+                let is_current = locale == current;
+
+                html! {
+                    <a
+                        class={classes!("locale-option", is_current.then_some("active"))}
+                        href={locale.path_to(&route)}
+                        hreflang={locale.code()}
+                        lang={locale.code()}
+                        aria-current={is_current.then_some("true")}
+                        onclick={Callback::from(move |_| locale.save())}
+                    >
+                        {locale.name()}
+                    </a>
+                }
+            }).collect::<Html>()}
+        </nav>
+    }
+}
+
+// ******************************* Catalogues ******************************* //
+// ************************************************************************** //
+
+/// The order of the parts of a written date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    DayMonthYear,
+    MonthDayYear,
+    YearMonthDay,
+}
+
+/// Every piece of UI text, in one locale.
+///
+/// Text that depends on a value is a function, so that each locale can
+/// arrange and pluralise it in its own way.
+pub struct Messages {
+    // Layout.
+    pub site_title: &'static str,
+    pub site_icon: &'static str,
+    pub skip_to_content: &'static str,
+    pub main_navigation: &'static str,
+    pub open_menu: &'static str,
+    pub close_menu: &'static str,
+    pub breadcrumb: &'static str,
+    pub language: &'static str,
+    pub colour_theme: &'static str,
+    pub system_theme: &'static str,
+    pub light_theme: &'static str,
+    pub dark_theme: &'static str,
+    pub high_contrast_theme: &'static str,
+    pub copyright: fn(&str, &str) -> String,
+    pub licensed_under: &'static str,
+    pub last_built: &'static str,
+    pub built_from: fn(&str) -> String,
+
+    // Routes.
+    pub home: &'static str,
+    pub skills: &'static str,
+    pub achievements: &'static str,
+    pub creations: &'static str,
+    pub projects: &'static str,
+    pub articles: &'static str,
+    pub education: &'static str,
+    pub experiences: &'static str,
+    pub contact: &'static str,
+    pub cv: &'static str,
+    pub page_not_found: &'static str,
+    pub not_found: &'static str,
+
+    // Pages.
+    pub skills_intro: &'static str,
+    pub achievements_intro: &'static str,
+    pub creations_intro: &'static str,
+    pub projects_intro: &'static str,
+    pub articles_intro: &'static str,
+    pub education_intro: &'static str,
+    pub experience_intro: &'static str,
+    pub contact_intro: &'static str,
+    pub send_a_message: &'static str,
+    pub sort_by: &'static str,
+    pub group_by_category: &'static str,
+    pub cv_intro: &'static str,
+    pub cv_sections: &'static str,
+    pub cv_areas: &'static str,
+    pub sub_areas: &'static str,
+    pub nothing_in_area: &'static str,
+    pub about: &'static str,
+    pub print: &'static str,
+    pub error_404: &'static str,
+    pub error_404_detail: &'static str,
+    pub error_404_help: &'static str,
+    pub emergency_exit: &'static str,
+    pub error_title: &'static str,
+    pub error_detail: &'static str,
+    pub error_contact: &'static str,
+
+    // Content.
+    pub competency: &'static str,
+    pub competencies: [&'static str; 5],
+    pub competency_by_area: &'static str,
+    pub experience: &'static str,
+    pub years: fn(Year) -> String,
+    pub present: &'static str,
+    pub progress: &'static str,
+    pub no_achievements: &'static str,
+    pub no_creations: &'static str,
+    pub no_projects: &'static str,
+    pub no_qualifications: &'static str,
+    pub no_roles: &'static str,
+    pub achievement_count: fn(usize) -> String,
+    pub creation_count: fn(usize) -> String,
+    pub project_count: fn(usize) -> String,
+    pub qualification_count: fn(usize) -> String,
+    pub role_count: fn(usize) -> String,
+    pub used_in: fn(&[String]) -> String,
+    pub other_category: &'static str,
+    pub declared_order: &'static str,
+    pub competency_order: &'static str,
+    pub recency_order: &'static str,
+    pub work: &'static str,
+    pub volunteering: &'static str,
+    pub active: &'static str,
+    pub archived: &'static str,
+    pub repository: &'static str,
+    pub live_demo: &'static str,
+    pub releases: &'static str,
+    pub no_releases: &'static str,
+    pub gallery: &'static str,
+    pub view_image: fn(&str) -> String,
+    pub previous_image: &'static str,
+    pub next_image: &'static str,
+    pub close: &'static str,
+    pub months: [&'static str; 12],
+    pub short_months: [&'static str; 12],
+    pub date_order: DateOrder,
+
+    // Contact.
+    pub download_contact_card: &'static str,
+    pub name: &'static str,
+    pub email: &'static str,
+    pub message: &'static str,
+    pub leave_empty: &'static str,
+    pub send: &'static str,
+    pub open_in_email_app: &'static str,
+    pub sending: &'static str,
+    pub message_sent: &'static str,
+    pub message_drafted: &'static str,
+    pub name_length_error: fn(usize) -> String,
+    pub email_error: &'static str,
+    pub message_length_error: fn(usize, usize) -> String,
+    pub network_error: fn(&str) -> String,
+    pub status_error: fn(u16) -> String,
+    pub unavailable_error: &'static str,
+}
+
+impl Messages {
+    /// Returns the title of `route`, as used in navigation and breadcrumbs.
+    pub fn route_title(&self, route: &Route) -> String {
+        match route {
+            Route::Home => self.home.to_string(),
+            Route::Skills => self.skills.to_string(),
+            Route::Achievements => self.achievements.to_string(),
+            Route::Creations => self.creations.to_string(),
+            Route::Projects => self.projects.to_string(),
+            Route::Articles => self.articles.to_string(),
+            Route::Education => self.education.to_string(),
+            Route::Experience => self.experiences.to_string(),
+            Route::Contact => self.contact.to_string(),
+            Route::Cv => self.cv.to_string(),
+            Route::NotFound => self.page_not_found.to_string(),
+            Route::Skill { skill: name }
+            | Route::Achievement { achievement: name }
+            | Route::Creation { creation: name }
+            | Route::Project { project: name }
+            | Route::Article { article: name }
+            | Route::Qualification {
+                qualification: name,
+            }
+            | Route::Role { role: name }
+            | Route::Area { area: name } => name.clone(),
+        }
+    }
+
+    /// Returns the name of a competency level.
+    pub const fn competency_name(&self, competency: Competency) -> &'static str {
+        self.competencies[competency as usize]
+    }
+
+    /// Returns the name of a skill order.
+    pub const fn skill_order(&self, order: SkillOrder) -> &'static str {
+        match order {
+            SkillOrder::Declared => self.declared_order,
+            SkillOrder::Competency => self.competency_order,
+            SkillOrder::Recency => self.recency_order,
+        }
+    }
+
+    /// Returns the name of a kind of experience.
+    pub const fn experience_kind(&self, kind: ExperienceKind) -> &'static str {
+        match kind {
+            ExperienceKind::Work => self.work,
+            ExperienceKind::Volunteering => self.volunteering,
+        }
+    }
+
+    /// Returns the name of a project status.
+    pub const fn project_status(&self, status: ProjectStatus) -> &'static str {
+        match status {
+            ProjectStatus::Active => self.active,
+            ProjectStatus::Archived => self.archived,
+        }
+    }
+
+    /// Returns the heading of a CV section.
+    pub const fn cv_section(&self, section: CvSection) -> &'static str {
+        match section {
+            CvSection::Bio => self.about,
+            CvSection::Skills => self.skills,
+            CvSection::Experience => self.experiences,
+            CvSection::Education => self.education,
+            CvSection::Achievements => self.achievements,
+            CvSection::Creations => self.creations,
+            CvSection::Projects => self.projects,
+        }
+    }
+
+    /// Returns the name of a colour theme.
+    pub const fn theme(&self, theme: Theme) -> &'static str {
+        match theme {
+            Theme::System => self.system_theme,
+            Theme::Light => self.light_theme,
+            Theme::Dark => self.dark_theme,
+            Theme::HighContrast => self.high_contrast_theme,
+        }
+    }
+
+    /// Returns the message shown for an invalid contact form field.
+    pub fn field_error(&self, error: FieldError) -> String {
+        match error {
+            FieldError::NameLength => (self.name_length_error)(crate::MAX_NAME_LENGTH),
+            FieldError::Email => self.email_error.to_string(),
+            FieldError::MessageLength => {
+                (self.message_length_error)(crate::MIN_MESSAGE_LENGTH, crate::MAX_MESSAGE_LENGTH)
+            }
+        }
+    }
+
+    /// Returns the message shown when a contact form message can't be sent.
+    pub fn contact_error(&self, error: &ContactError) -> String {
+        match error {
+            ContactError::Network(detail) => (self.network_error)(detail),
+            ContactError::Status(status) => (self.status_error)(*status),
+            ContactError::Unavailable => self.unavailable_error.to_string(),
+        }
+    }
+}
+
+// ***************************** Localised Text ***************************** //
+// ************************************************************************** //
+
+/// Content text with optional translations, which falls back to the text in
+/// the default locale.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Localised {
+    text: String,
+    translations: Vec<(Locale, String)>,
+}
+
+impl Localised {
+    /// Adds the text's translation into `locale`.
+    #[must_use]
+    pub fn with(mut self, locale: Locale, text: &str) -> Self {
+        self.translations.push((locale, text.to_string()));
+        self
+    }
+
+    /// Returns the text in `locale`, or in the default locale if it hasn't
+    /// been translated.
+    pub fn get(&self, locale: Locale) -> &str {
+        self.translations
+            .iter()
+            .find(|(translation, _)| *translation == locale)
+            .map_or(&self.text, |(_, text)| text)
+    }
+}
+
+impl From<&str> for Localised {
+    fn from(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            translations: Vec::new(),
+        }
+    }
+}
+
+impl Display for Localised {
+    /// Writes the text in the [current](Locale::current) locale.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get(Locale::current()))
+    }
+}
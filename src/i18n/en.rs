@@ -0,0 +1,159 @@
+//! The English catalogue, which is also the text of the default locale.
+
+use super::{DateOrder, Messages};
+
+pub const MESSAGES: Messages = Messages {
+    // Layout.
+    site_title: "Hello, world!",
+    site_icon: "Website Icon",
+    skip_to_content: "Skip to content",
+    main_navigation: "Main",
+    open_menu: "Open menu",
+    close_menu: "Close menu",
+    breadcrumb: "Breadcrumb",
+    language: "Language",
+    colour_theme: "Colour theme",
+    system_theme: "System theme",
+    light_theme: "Light theme",
+    dark_theme: "Dark theme",
+    high_contrast_theme: "High-contrast theme",
+    copyright: |year, name| format!("Copyright (C) {year} {name}. "),
+    licensed_under: "Licensed under the ",
+    last_built: "Last built ",
+    built_from: |commit| format!(" from {commit}."),
+
+    // Routes.
+    home: "Home",
+    skills: "Skills",
+    achievements: "Achievements",
+    creations: "Creations",
+    projects: "Projects",
+    articles: "Articles",
+    education: "Education",
+    experiences: "Experience",
+    contact: "Contact",
+    cv: "CV",
+    page_not_found: "Page Not Found",
+    not_found: "Not Found",
+
+    // Pages.
+    skills_intro: "These are my skills.",
+    achievements_intro: "These are my achievements.",
+    creations_intro: "These are my creations.",
+    projects_intro: "These are the software projects I've written or maintain.",
+    articles_intro: "These are my articles.",
+    education_intro: "This is my education.",
+    experience_intro: "This is my work and volunteering experience.",
+    contact_intro: "If you have any questions, feel free to contact me.",
+    send_a_message: "Send me a message",
+    sort_by: "Sort by: ",
+    group_by_category: " Group by category",
+    cv_intro: "Choose what to include, then print this page.",
+    cv_sections: "Sections",
+    cv_areas: "Areas",
+    sub_areas: "Sub-areas: ",
+    nothing_in_area: "There's nothing in this area yet.",
+    about: "About",
+    print: "Print",
+    error_404: "Error 404",
+    error_404_detail: "Error 404 : Page Not Found",
+    error_404_help: "Please navigate your way back to the main site",
+    emergency_exit: "EMERGENCY EXIT:",
+    error_title: "Something Went Wrong",
+    error_detail: "Sorry, this site couldn't be loaded. Please try again later.",
+    error_contact: "You can still reach me by email:",
+
+    // Content.
+    competency: "Competency: ",
+    competencies: ["None", "Novice", "Intermediate", "Advanced", "Expert"],
+    competency_by_area: "Competency by area",
+    experience: "Experience: ",
+    years: |years| match years {
+        0 => String::from("Less than a year"),
+        1 => String::from("1 year"),
+        years => format!("{years} years"),
+    },
+    present: "present",
+    progress: "Progress",
+    no_achievements: "Not used in any achievements yet.",
+    no_creations: "Not used in any creations yet.",
+    no_projects: "Not used in any projects yet.",
+    no_qualifications: "Not used in any qualifications yet.",
+    no_roles: "Not used in any roles yet.",
+    achievement_count: |count| match count {
+        1 => String::from("1 achievement"),
+        count => format!("{count} achievements"),
+    },
+    creation_count: |count| match count {
+        1 => String::from("1 creation"),
+        count => format!("{count} creations"),
+    },
+    project_count: |count| match count {
+        1 => String::from("1 project"),
+        count => format!("{count} projects"),
+    },
+    qualification_count: |count| match count {
+        1 => String::from("1 qualification"),
+        count => format!("{count} qualifications"),
+    },
+    role_count: |count| match count {
+        1 => String::from("1 role"),
+        count => format!("{count} roles"),
+    },
+    used_in: |counts| format!("Used in {}.", counts.join(" and ")),
+    other_category: "Other",
+    declared_order: "Default",
+    competency_order: "Competency",
+    recency_order: "Recently used",
+    work: "Work",
+    volunteering: "Volunteering",
+    active: "Active",
+    archived: "Archived",
+    repository: "Source code",
+    live_demo: "Live demo",
+    releases: "Releases",
+    no_releases: "No releases yet.",
+    gallery: "Gallery",
+    view_image: |alt| format!("View full size: {alt}"),
+    previous_image: "Previous image",
+    next_image: "Next image",
+    close: "Close",
+    months: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    short_months: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    date_order: DateOrder::DayMonthYear,
+
+    // Contact.
+    download_contact_card: " Download my contact card (.vcf)",
+    name: "Name",
+    email: "Email",
+    message: "Message",
+    leave_empty: "Leave this field empty",
+    send: "Send",
+    open_in_email_app: "Open in email app",
+    sending: "Sending...",
+    message_sent: "Thank you! Your message is on its way.",
+    message_drafted: "Your message is ready in your email app. Send it from there to reach me.",
+    name_length_error: |max| format!("Please enter a name of up to {max} characters."),
+    email_error: "Please enter a valid email address.",
+    message_length_error: |min, max| {
+        format!("Please enter a message of {min} to {max} characters.")
+    },
+    network_error: |error| format!("Your message couldn't be sent: {error}"),
+    status_error: |status| format!("Your message couldn't be sent (error {status})."),
+    unavailable_error: "Your message couldn't be sent from this browser.",
+};
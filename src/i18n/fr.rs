@@ -0,0 +1,160 @@
+//! The French catalogue.
+
+use super::{DateOrder, Messages};
+
+pub const MESSAGES: Messages = Messages {
+    // Layout.
+    site_title: "Bonjour, le monde !",
+    site_icon: "Icône du site",
+    skip_to_content: "Aller au contenu",
+    main_navigation: "Principale",
+    open_menu: "Ouvrir le menu",
+    close_menu: "Fermer le menu",
+    breadcrumb: "Fil d'Ariane",
+    language: "Langue",
+    colour_theme: "Thème de couleurs",
+    system_theme: "Thème du système",
+    light_theme: "Thème clair",
+    dark_theme: "Thème sombre",
+    high_contrast_theme: "Thème à contraste élevé",
+    copyright: |year, name| format!("Copyright (C) {year} {name}. "),
+    licensed_under: "Sous licence ",
+    last_built: "Dernière compilation le ",
+    built_from: |commit| format!(", à partir de {commit}."),
+
+    // Routes.
+    home: "Accueil",
+    skills: "Compétences",
+    achievements: "Réussites",
+    creations: "Créations",
+    projects: "Projets",
+    articles: "Articles",
+    education: "Formation",
+    experiences: "Expérience",
+    contact: "Contact",
+    cv: "CV",
+    page_not_found: "Page introuvable",
+    not_found: "Introuvable",
+
+    // Pages.
+    skills_intro: "Voici mes compétences.",
+    achievements_intro: "Voici mes réussites.",
+    creations_intro: "Voici mes créations.",
+    projects_intro: "Voici les projets logiciels que j'ai écrits ou que je maintiens.",
+    articles_intro: "Voici mes articles.",
+    education_intro: "Voici ma formation.",
+    experience_intro: "Voici mon expérience professionnelle et bénévole.",
+    contact_intro: "Si vous avez des questions, n'hésitez pas à me contacter.",
+    send_a_message: "Envoyez-moi un message",
+    sort_by: "Trier par : ",
+    group_by_category: " Regrouper par catégorie",
+    cv_intro: "Choisissez ce qu'il faut inclure, puis imprimez cette page.",
+    cv_sections: "Sections",
+    cv_areas: "Domaines",
+    sub_areas: "Sous-domaines : ",
+    nothing_in_area: "Il n'y a encore rien dans ce domaine.",
+    about: "À propos",
+    print: "Imprimer",
+    error_404: "Erreur 404",
+    error_404_detail: "Erreur 404 : page introuvable",
+    error_404_help: "Veuillez retourner sur le site principal",
+    emergency_exit: "SORTIE DE SECOURS :",
+    error_title: "Une erreur est survenue",
+    error_detail: "Désolée, ce site n'a pas pu être chargé. Veuillez réessayer plus tard.",
+    error_contact: "Vous pouvez toujours me joindre par e-mail :",
+
+    // Content.
+    competency: "Niveau : ",
+    competencies: ["Aucun", "Débutant", "Intermédiaire", "Avancé", "Expert"],
+    competency_by_area: "Niveau par domaine",
+    experience: "Expérience : ",
+    years: |years| match years {
+        0 => String::from("Moins d'un an"),
+        1 => String::from("1 an"),
+        years => format!("{years} ans"),
+    },
+    present: "aujourd'hui",
+    progress: "Progression",
+    no_achievements: "Utilisée dans aucune réussite pour l'instant.",
+    no_creations: "Utilisée dans aucune création pour l'instant.",
+    no_projects: "Utilisée dans aucun projet pour l'instant.",
+    no_qualifications: "Utilisée dans aucune formation pour l'instant.",
+    no_roles: "Utilisée dans aucun poste pour l'instant.",
+    achievement_count: |count| match count {
+        1 => String::from("1 réussite"),
+        count => format!("{count} réussites"),
+    },
+    creation_count: |count| match count {
+        1 => String::from("1 création"),
+        count => format!("{count} créations"),
+    },
+    project_count: |count| match count {
+        1 => String::from("1 projet"),
+        count => format!("{count} projets"),
+    },
+    qualification_count: |count| match count {
+        1 => String::from("1 formation"),
+        count => format!("{count} formations"),
+    },
+    role_count: |count| match count {
+        1 => String::from("1 poste"),
+        count => format!("{count} postes"),
+    },
+    used_in: |counts| format!("Utilisée dans {}.", counts.join(" et ")),
+    other_category: "Autres",
+    declared_order: "Par défaut",
+    competency_order: "Niveau",
+    recency_order: "Utilisation récente",
+    work: "Travail",
+    volunteering: "Bénévolat",
+    active: "Actif",
+    archived: "Archivé",
+    repository: "Code source",
+    live_demo: "Démo en ligne",
+    releases: "Versions",
+    no_releases: "Aucune version pour l'instant.",
+    gallery: "Galerie",
+    view_image: |alt| format!("Voir en grand : {alt}"),
+    previous_image: "Image précédente",
+    next_image: "Image suivante",
+    close: "Fermer",
+    months: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    short_months: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    date_order: DateOrder::DayMonthYear,
+
+    // Contact.
+    download_contact_card: " Télécharger ma carte de contact (.vcf)",
+    name: "Nom",
+    email: "E-mail",
+    message: "Message",
+    leave_empty: "Laissez ce champ vide",
+    send: "Envoyer",
+    open_in_email_app: "Ouvrir dans l'application de messagerie",
+    sending: "Envoi en cours...",
+    message_sent: "Merci ! Votre message est en route.",
+    message_drafted: "Votre message est prêt dans votre application de messagerie. Envoyez-le depuis celle-ci pour me joindre.",
+    name_length_error: |max| format!("Veuillez saisir un nom de {max} caractères au maximum."),
+    email_error: "Veuillez saisir une adresse e-mail valide.",
+    message_length_error: |min, max| {
+        format!("Veuillez saisir un message de {min} à {max} caractères.")
+    },
+    network_error: |error| format!("Votre message n'a pas pu être envoyé : {error}"),
+    status_error: |status| format!("Votre message n'a pas pu être envoyé (erreur {status})."),
+    unavailable_error: "Votre message ne peut pas être envoyé depuis ce navigateur.",
+};
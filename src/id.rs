@@ -0,0 +1,133 @@
+//! Typed identifiers for content items.
+//!
+//! An item's [`Id`] is a hash of its name, so it never has to be picked by hand
+//! and stays the same from build to build. Names are what items are looked up
+//! and routed by, so they're already unique within a list, and
+//! `kira-content check` reports any that aren't.
+//!
+//! The hash is [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/), rather
+//! than [`std::hash::DefaultHasher`], whose output may change between Rust
+//! releases.
+
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+use yew::virtual_dom::Key;
+
+#[cfg(test)]
+#[allow(clippy::items_after_test_module)]
+mod tests {
+    use super::*;
+    use crate::{Creation, Skill};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", 0xcbf2_9ce4_8422_2325)]
+    #[case("a", 0xaf63_dc4c_8601_ec8c)]
+    #[case("foobar", 0x8594_4171_f739_67e8)]
+    fn id_from_name_matches_fnv_1a(#[case] name: &str, #[case] expected: u64) {
+        assert_eq!(Id::<Skill>::from_name(name).to_u64(), expected);
+    }
+
+    #[rstest]
+    fn id_from_name_is_stable_and_distinct() {
+        assert_eq!(Id::<Skill>::from_name("Rust"), Id::from_name("Rust"));
+        assert_ne!(Id::<Skill>::from_name("C++"), Id::from_name("C#"));
+        assert_ne!(Id::<Creation>::from_name("Rust"), Id::from_name("rust"));
+    }
+
+    #[rstest]
+    fn id_displays_as_fixed_width_hex() {
+        assert_eq!(Id::<Skill>::from_name("").to_string(), "cbf29ce484222325");
+        assert_eq!(Id::<Skill>::from_name("").to_string().len(), 16);
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// The identifier of a content item of type `T`, like a [`Skill`](crate::Skill).
+///
+/// Ids of different types can't be mixed up, so a skill's id can't be used to
+/// find a creation. They're used as Yew `key`s, so must be unique among the
+/// items rendered side by side.
+pub struct Id<T> {
+    hash: u64,
+    kind: PhantomData<fn() -> T>,
+}
+
+impl<T> Id<T> {
+    /// Returns the id of the item with the given name.
+    pub fn from_name(name: &str) -> Self {
+        Self {
+            hash: name.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            }),
+            kind: PhantomData,
+        }
+    }
+
+    /// Returns the id's underlying hash.
+    pub const fn to_u64(self) -> u64 {
+        self.hash
+    }
+}
+
+// The derives would only apply when `T` implements each trait too, even though
+// `T` is only a marker, so they're implemented by hand.
+
+impl<T> Clone for Id<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Id<T> {}
+
+impl<T> PartialEq for Id<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash
+    }
+}
+
+impl<T> Eq for Id<T> {}
+
+impl<T> PartialOrd for Id<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Id<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.hash.cmp(&other.hash)
+    }
+}
+
+impl<T> Hash for Id<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+    }
+}
+
+impl<T> Debug for Id<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Id({self})")
+    }
+}
+
+impl<T> Display for Id<T> {
+    /// Writes the id as 16 hexadecimal digits.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:016x}", self.hash)
+    }
+}
+
+impl<T> From<Id<T>> for Key {
+    fn from(id: Id<T>) -> Self {
+        Self::from(id.to_string())
+    }
+}
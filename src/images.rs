@@ -0,0 +1,323 @@
+//! Responsive images, built from the originals in `media/`.
+//!
+//! `kira-content images` resizes each image content uses to the
+//! [`VARIANT_WIDTHS`] narrower than it, encodes every size in the
+//! [`ImageEntry::formats`] for the original's format, and records the image's
+//! size in `media/manifest.json`. Trunk runs it before each build, copying
+//! the variants to `/images/`.
+//!
+//! The variants are cached in the target directory, so images are only
+//! encoded again when their originals change. The manifest is only written
+//! when it changes, so it only dirties the working tree when there are new
+//! images to commit along with it.
+//!
+//! The manifest is compiled into the site, so [`picture`] can offer the
+//! browser every variant and reserve the image's space before it loads. Images
+//! missing from the manifest, like those added since it was last built, fall
+//! back to a plain `<img>` of the original.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod pipeline;
+
+use crate::slug;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, sync::OnceLock};
+use yew::prelude::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn entry() -> ImageEntry {
+        ImageEntry {
+            width: 800,
+            height: 450,
+            widths: vec![320, 640, 800],
+            fallback: ImageFormat::Png,
+        }
+    }
+
+    #[rstest]
+    #[case("/media/render.png", Some(ImageFormat::Png))]
+    #[case("/media/photo.JPG", Some(ImageFormat::Jpeg))]
+    #[case("/media/photo.jpeg", Some(ImageFormat::Jpeg))]
+    #[case("/media/theme.ogg", None)]
+    #[case("/media/render", None)]
+    fn image_format_from_path_works_as_expected(
+        #[case] src: &str,
+        #[case] expected: Option<ImageFormat>,
+    ) {
+        assert_eq!(ImageFormat::from_path(src), expected);
+    }
+
+    #[rstest]
+    #[case(
+        "/media/render.png",
+        320,
+        ImageFormat::Avif,
+        "/images/media-render-png-320.avif"
+    )]
+    #[case(
+        "/media/blender/Donut Render.png",
+        640,
+        ImageFormat::Webp,
+        "/images/media-blender-donut-render-png-640.webp"
+    )]
+    #[case(
+        "/media/photo.jpeg",
+        800,
+        ImageFormat::Jpeg,
+        "/images/media-photo-jpeg-800.jpg"
+    )]
+    fn variant_src_works_as_expected(
+        #[case] src: &str,
+        #[case] width: u32,
+        #[case] format: ImageFormat,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(variant_src(src, width, format), expected);
+    }
+
+    #[rstest]
+    fn image_entry_srcset_lists_every_width() {
+        assert_eq!(
+            entry().srcset("/media/render.png", ImageFormat::Webp),
+            "/images/media-render-png-320.webp 320w, \
+             /images/media-render-png-640.webp 640w, \
+             /images/media-render-png-800.webp 800w"
+        );
+    }
+
+    #[rstest]
+    #[case(ImageFormat::Png, vec![ImageFormat::Avif, ImageFormat::Webp, ImageFormat::Png])]
+    #[case(ImageFormat::Jpeg, vec![ImageFormat::Avif, ImageFormat::Jpeg])]
+    fn image_entry_formats_works_as_expected(
+        #[case] fallback: ImageFormat,
+        #[case] expected: Vec<ImageFormat>,
+    ) {
+        assert_eq!(
+            ImageEntry {
+                fallback,
+                ..entry()
+            }
+            .formats(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(800, 450)]
+    #[case(320, 180)]
+    #[case(333, 187)]
+    fn image_entry_height_at_keeps_the_aspect_ratio(#[case] width: u32, #[case] expected: u32) {
+        assert_eq!(entry().height_at(width), expected);
+    }
+
+    #[rstest]
+    fn image_manifest_round_trips_through_json() {
+        let manifest = ImageManifest(BTreeMap::from([("/media/render.png".to_string(), entry())]));
+
+        let json = manifest.to_json().expect("Failed to serialise manifest");
+
+        assert_eq!(ImageManifest::from_json(&json).ok(), Some(manifest));
+        assert_eq!(
+            ImageManifest::from_json("{}").ok(),
+            Some(ImageManifest::default())
+        );
+    }
+}
+
+/// The widths, in pixels, that images are resized to, narrowest first.
+pub const VARIANT_WIDTHS: [u32; 5] = [320, 640, 960, 1280, 1920];
+
+/// The directory the variants are served from.
+pub const VARIANT_DIRECTORY: &str = "/images";
+
+/// The manifest written by the last run of `kira-content images`.
+const MANIFEST: &str = include_str!("../media/manifest.json");
+
+/// The formats images are encoded in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageFormat {
+    Avif,
+    Webp,
+    Png,
+    Jpeg,
+}
+
+impl ImageFormat {
+    /// Returns the formats offered before this one when it's the fallback,
+    /// most compact first.
+    ///
+    /// The WebP encoder is lossless, which usually makes a photo larger than
+    /// its JPEG, so JPEGs are only offered as AVIF.
+    pub const fn modern(self) -> &'static [Self] {
+        match self {
+            Self::Jpeg => &[Self::Avif],
+            Self::Avif | Self::Webp | Self::Png => &[Self::Avif, Self::Webp],
+        }
+    }
+
+    /// Returns the format of an original image, from its extension, or `None`
+    /// if it isn't a PNG or JPEG.
+    pub fn from_path(src: &str) -> Option<Self> {
+        let (_, extension) = src.rsplit_once('.')?;
+
+        match extension.to_ascii_lowercase().as_str() {
+            "png" => Some(Self::Png),
+            "jpg" | "jpeg" => Some(Self::Jpeg),
+            _ => None,
+        }
+    }
+
+    /// Returns the extension of the format's files.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Avif => "avif",
+            Self::Webp => "webp",
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+        }
+    }
+
+    /// Returns the format's MIME type, as used in `<source type>`.
+    pub const fn mime_type(self) -> &'static str {
+        match self {
+            Self::Avif => "image/avif",
+            Self::Webp => "image/webp",
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+        }
+    }
+}
+
+/// Returns the address of an image's variant, like
+/// `/images/media-render-png-640.webp` for `/media/render.png`.
+///
+/// Variants are named after the [`slug`] of the original's whole path,
+/// extension included, so originals with the same file name in different
+/// directories or formats get different names. Paths that only differ in
+/// punctuation or case, like `a b.png` and `a-b.png`, still share them, which
+/// [`pipeline::build`] rejects.
+pub fn variant_src(src: &str, width: u32, format: ImageFormat) -> String {
+    format!(
+        "{VARIANT_DIRECTORY}/{}-{width}.{}",
+        slug(src),
+        format.extension()
+    )
+}
+
+/// An image's size and the variants built from it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageEntry {
+    /// The original's width, in pixels.
+    pub width: u32,
+    /// The original's height, in pixels.
+    pub height: u32,
+    /// The widths of the variants, narrowest first.
+    pub widths: Vec<u32>,
+    /// The format of the variants for browsers without AVIF or WebP support.
+    pub fallback: ImageFormat,
+}
+
+impl ImageEntry {
+    /// Returns the height of the image when resized to `width`, rounded to
+    /// the nearest pixel.
+    pub fn height_at(&self, width: u32) -> u32 {
+        let height = (u64::from(self.height) * u64::from(width) + u64::from(self.width) / 2)
+            / u64::from(self.width.max(1));
+
+        u32::try_from(height).unwrap_or(u32::MAX)
+    }
+
+    /// Returns the formats the image's variants are encoded in: the modern
+    /// ones for its fallback, then the fallback.
+    pub fn formats(&self) -> Vec<ImageFormat> {
+        self.fallback
+            .modern()
+            .iter()
+            .copied()
+            .chain(std::iter::once(self.fallback))
+            .collect()
+    }
+
+    /// Returns the `srcset` of the image's variants in `format`.
+    pub fn srcset(&self, src: &str, format: ImageFormat) -> String {
+        self.widths
+            .iter()
+            .map(|width| format!("{} {width}w", variant_src(src, *width, format)))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+/// The [`ImageEntry`] of each image, keyed by the original's `src`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ImageManifest(pub BTreeMap<String, ImageEntry>);
+
+impl ImageManifest {
+    /// Returns the manifest compiled into the site, or an empty one if it
+    /// can't be read.
+    pub fn current() -> &'static Self {
+        static CURRENT: OnceLock<ImageManifest> = OnceLock::new();
+
+        CURRENT.get_or_init(|| Self::from_json(MANIFEST).unwrap_or_default())
+    }
+
+    /// Returns the entry of the image with the given `src`.
+    pub fn get(&self, src: &str) -> Option<&ImageEntry> {
+        self.0.get(src)
+    }
+
+    /// Returns the manifest read from JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON isn't a manifest.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the manifest as pretty-printed JSON, ending with a newline.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialisation fails.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self).map(|json| json + "\n")
+    }
+}
+
+/// Returns an image as a `<picture>` of its variants, which the browser picks
+/// from by format and by the width given in `sizes`, or as a plain `<img>` if
+/// it isn't in the manifest.
+///
+/// The image is loaded lazily, and `classes` are given to the `<img>`.
+pub fn picture(src: &str, alt: String, sizes: &'static str, classes: Classes) -> Html {
+    let Some(entry) = ImageManifest::current().get(src) else {
+        return html! { <img class={classes} src={src.to_string()} {alt} loading={"lazy"} /> };
+    };
+    let widest = entry.widths.last().copied().unwrap_or(entry.width);
+
+    html! {
+        <picture>
+            {entry.fallback.modern().iter().copied().map(|format| html! {
+                <source type={format.mime_type()} srcset={entry.srcset(src, format)} {sizes} />
+            }).collect::<Html>()}
+            <img
+                class={classes}
+                src={variant_src(src, widest, entry.fallback)}
+                srcset={entry.srcset(src, entry.fallback)}
+                {sizes}
+                width={widest.to_string()}
+                height={entry.height_at(widest).to_string()}
+                {alt}
+                loading={"lazy"}
+                decoding={"async"}
+            />
+        </picture>
+    }
+}
@@ -0,0 +1,333 @@
+//! Building the variants of images and their manifest, natively.
+//!
+//! Encoding AVIF is slow, so variants are built into a cache that outlives
+//! each site build, and those newer than their original are kept rather than
+//! built again. [`copy`] then copies the ones still in use to where they're
+//! served from.
+
+use super::{variant_src, ImageEntry, ImageFormat, ImageManifest, VARIANT_WIDTHS};
+use crate::slug;
+use image::{
+    codecs::{avif::AvifEncoder, jpeg::JpegEncoder, png::PngEncoder, webp::WebPEncoder},
+    imageops::FilterType,
+    DynamicImage, ImageError,
+};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, File},
+    io::{self, BufWriter},
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb, RgbImage, Rgba, RgbaImage};
+    use rstest::rstest;
+
+    /// Returns an empty directory for a test to write to.
+    fn scratch(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("kira-images-{name}"));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).expect("Failed to create scratch directory");
+        directory
+    }
+
+    #[rstest]
+    #[case(200, vec![200])]
+    #[case(320, vec![320])]
+    #[case(800, vec![320, 640, 800])]
+    #[case(4000, vec![320, 640, 960, 1280, 1920])]
+    fn variant_widths_works_as_expected(#[case] width: u32, #[case] expected: Vec<u32>) {
+        assert_eq!(variant_widths(width), expected);
+    }
+
+    #[rstest]
+    fn build_writes_every_variant_and_its_entry() {
+        let root = scratch("build-root");
+        let out = scratch("build-out");
+        fs::create_dir_all(root.join("media")).expect("Failed to create media directory");
+        RgbaImage::from_pixel(400, 100, Rgba([255, 0, 128, 255]))
+            .save(root.join("media/banner.png"))
+            .expect("Failed to save image");
+
+        let manifest = build(&root, ["/media/banner.png"], &out).expect("Failed to build images");
+
+        assert_eq!(
+            manifest.get("/media/banner.png"),
+            Some(&ImageEntry {
+                width: 400,
+                height: 100,
+                widths: vec![320, 400],
+                fallback: ImageFormat::Png,
+            })
+        );
+        // The image crate can encode AVIF, but not decode it.
+        assert!(variant_path(&out, "/media/banner.png", 320, ImageFormat::Avif).is_file());
+        for format in [ImageFormat::Webp, ImageFormat::Png] {
+            let variant = variant_path(&out, "/media/banner.png", 320, format);
+            let (width, height) = image::image_dimensions(&variant).expect("Variant missing");
+
+            assert_eq!((width, height), (320, 80), "{}", variant.display());
+        }
+    }
+
+    #[rstest]
+    fn copy_leaves_unused_variants_behind() {
+        let root = scratch("copy-root");
+        let cache = scratch("copy-cache");
+        let out = scratch("copy-out");
+        fs::create_dir_all(root.join("media")).expect("Failed to create media directory");
+        RgbaImage::from_pixel(200, 100, Rgba([0, 128, 255, 255]))
+            .save(root.join("media/icon.png"))
+            .expect("Failed to save image");
+        fs::write(cache.join("media-removed-png-320.png"), []).expect("Failed to write variant");
+
+        let manifest = build(&root, ["/media/icon.png"], &cache).expect("Failed to build images");
+        copy(&manifest, &cache, &out).expect("Failed to copy images");
+
+        assert!(variant_path(&out, "/media/icon.png", 200, ImageFormat::Webp).is_file());
+        assert!(!out.join("media-removed-png-320.png").exists());
+    }
+
+    #[rstest]
+    fn build_rejects_missing_and_unsupported_images() {
+        let root = scratch("reject-root");
+        let out = scratch("reject-out");
+
+        assert!(matches!(
+            build(&root, ["/media/missing.png"], &out),
+            Err(PipelineError::Image(src, _)) if src == "/media/missing.png"
+        ));
+        assert!(matches!(
+            build(&root, ["/media/render.gif"], &out),
+            Err(PipelineError::Unsupported(src)) if src == "/media/render.gif"
+        ));
+    }
+
+    #[rstest]
+    fn build_keeps_images_differing_in_format_apart() {
+        let root = scratch("formats-root");
+        let out = scratch("formats-out");
+        fs::create_dir_all(root.join("media")).expect("Failed to create media directory");
+        RgbaImage::from_pixel(200, 100, Rgba([255, 0, 0, 255]))
+            .save(root.join("media/photo.png"))
+            .expect("Failed to save image");
+        RgbImage::from_pixel(100, 100, Rgb([0, 0, 255]))
+            .save(root.join("media/photo.jpg"))
+            .expect("Failed to save image");
+
+        build(&root, ["/media/photo.png", "/media/photo.jpg"], &out)
+            .expect("Failed to build images");
+
+        for (src, width) in [("/media/photo.png", 200), ("/media/photo.jpg", 100)] {
+            let variant = variant_path(&out, src, width, ImageFormat::Avif);
+
+            assert!(variant.is_file(), "{}", variant.display());
+        }
+    }
+
+    #[rstest]
+    fn build_rejects_images_whose_variants_collide() {
+        let root = scratch("collide-root");
+        let out = scratch("collide-out");
+        fs::create_dir_all(root.join("media")).expect("Failed to create media directory");
+        for file in ["a b.png", "a-b.png"] {
+            RgbaImage::from_pixel(10, 10, Rgba([0, 0, 0, 255]))
+                .save(root.join("media").join(file))
+                .expect("Failed to save image");
+        }
+
+        assert!(matches!(
+            build(&root, ["/media/a b.png", "/media/a-b.png"], &out),
+            Err(PipelineError::Collision(first, src))
+                if first == "/media/a b.png" && src == "/media/a-b.png"
+        ));
+    }
+}
+
+/// The AVIF encoder's speed, from 1 (slowest, smallest) to 10.
+const AVIF_SPEED: u8 = 6;
+/// The AVIF and JPEG encoders' quality, from 1 to 100.
+const QUALITY: u8 = 80;
+
+/// The reasons images can't be built.
+#[derive(Debug)]
+pub enum PipelineError {
+    /// The image isn't a PNG or JPEG.
+    Unsupported(String),
+    /// Two images whose variants would have the same names, like `a b.png`
+    /// and `a-b.png`.
+    Collision(String, String),
+    /// The image couldn't be read, resized or encoded.
+    Image(String, ImageError),
+    /// The variants couldn't be written.
+    Io(io::Error),
+}
+
+impl Display for PipelineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported(src) => write!(f, "`{src}` isn't a PNG or JPEG image"),
+            Self::Collision(first, src) => write!(
+                f,
+                "`{src}` would have the same variants as `{first}`; rename one of them"
+            ),
+            Self::Image(src, error) => write!(f, "Failed to build `{src}`: {error}"),
+            Self::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for PipelineError {}
+
+impl From<io::Error> for PipelineError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Returns the widths to resize an image `width` pixels wide to: each of the
+/// [`VARIANT_WIDTHS`] narrower than it, and its own, up to the widest of
+/// them.
+pub fn variant_widths(width: u32) -> Vec<u32> {
+    let widest = width.min(VARIANT_WIDTHS[VARIANT_WIDTHS.len() - 1]);
+
+    VARIANT_WIDTHS
+        .into_iter()
+        .filter(|variant| *variant < widest)
+        .chain(std::iter::once(widest))
+        .collect()
+}
+
+/// Returns where a variant is written, inside the `out` directory that's
+/// served as [`VARIANT_DIRECTORY`](super::VARIANT_DIRECTORY).
+fn variant_path(out: &Path, src: &str, width: u32, format: ImageFormat) -> PathBuf {
+    let variant = variant_src(src, width, format);
+    let file = variant
+        .rsplit_once('/')
+        .map_or(variant.as_str(), |(_, file)| file);
+
+    out.join(file)
+}
+
+/// Returns `true` if `path` exists and was written after `source` last
+/// changed.
+fn is_fresh(path: &Path, source: SystemTime) -> bool {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .is_ok_and(|modified| modified >= source)
+}
+
+/// Builds the variants of each image into `out`, and returns their manifest.
+///
+/// Each `src` is an original's address, like `/media/render.png`, which is
+/// read from the same path under `root`.
+///
+/// # Errors
+///
+/// Returns an error if an image isn't a PNG or JPEG, can't be read or
+/// encoded, would have the same variants as another, or `out` can't be
+/// written to.
+pub fn build<'a>(
+    root: &Path,
+    sources: impl IntoIterator<Item = &'a str>,
+    out: &Path,
+) -> Result<ImageManifest, PipelineError> {
+    fs::create_dir_all(out)?;
+    let mut manifest = ImageManifest::default();
+    // The original each variant name was first used by, as variants are named
+    // after the slug of their original's path.
+    let mut names: HashMap<String, &str> = HashMap::new();
+
+    for src in sources {
+        if manifest.get(src).is_some() {
+            continue;
+        }
+        if let Some(first) = names.insert(slug(src), src) {
+            return Err(PipelineError::Collision(first.to_string(), src.to_string()));
+        }
+
+        let fallback = ImageFormat::from_path(src)
+            .ok_or_else(|| PipelineError::Unsupported(src.to_string()))?;
+        let original = root.join(src.trim_start_matches('/'));
+        let changed = fs::metadata(&original)
+            .and_then(|metadata| metadata.modified())
+            .unwrap_or_else(|_| SystemTime::now());
+        let image =
+            image::open(&original).map_err(|error| PipelineError::Image(src.to_string(), error))?;
+
+        let entry = ImageEntry {
+            width: image.width(),
+            height: image.height(),
+            widths: variant_widths(image.width()),
+            fallback,
+        };
+
+        for width in &entry.widths {
+            let formats = entry
+                .formats()
+                .into_iter()
+                .map(|format| (format, variant_path(out, src, *width, format)))
+                .filter(|(_, path)| !is_fresh(path, changed))
+                .collect::<Vec<(ImageFormat, PathBuf)>>();
+
+            if formats.is_empty() {
+                continue;
+            }
+
+            let resized = image.resize_exact(*width, entry.height_at(*width), FilterType::Lanczos3);
+
+            for (format, path) in formats {
+                encode(&resized, format, &path)
+                    .map_err(|error| PipelineError::Image(src.to_string(), error))?;
+            }
+        }
+
+        manifest.0.insert(src.to_string(), entry);
+    }
+
+    Ok(manifest)
+}
+
+/// Copies the variants in `manifest` from `cache`, where [`build`] wrote
+/// them, to `out`, leaving behind any of images content no longer uses.
+///
+/// # Errors
+///
+/// Returns an error if a variant is missing from `cache`, or `out` can't be
+/// written to.
+pub fn copy(manifest: &ImageManifest, cache: &Path, out: &Path) -> Result<(), PipelineError> {
+    fs::create_dir_all(out)?;
+
+    for (src, entry) in &manifest.0 {
+        for width in &entry.widths {
+            for format in entry.formats() {
+                fs::copy(
+                    variant_path(cache, src, *width, format),
+                    variant_path(out, src, *width, format),
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes `image` to `path` in `format`.
+fn encode(image: &DynamicImage, format: ImageFormat, path: &Path) -> Result<(), ImageError> {
+    let writer = BufWriter::new(File::create(path).map_err(ImageError::IoError)?);
+
+    match format {
+        ImageFormat::Avif => image.write_with_encoder(AvifEncoder::new_with_speed_quality(
+            writer, AVIF_SPEED, QUALITY,
+        )),
+        ImageFormat::Webp => image.write_with_encoder(WebPEncoder::new_lossless(writer)),
+        ImageFormat::Png => image.write_with_encoder(PngEncoder::new(writer)),
+        // JPEG has no alpha channel.
+        ImageFormat::Jpeg => DynamicImage::ImageRgb8(image.to_rgb8())
+            .write_with_encoder(JpegEncoder::new_with_quality(writer, QUALITY)),
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{
+    data::Portfolio, switch, Breadcrumbs, ContactDetails, Locale, LocaleSwitcher, Route,
+    ThemeToggle,
+};
+use std::rc::Rc;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+/// The pages linked from the navigation, in order.
+pub const NAV_ENTRIES: [Route; 10] = [
+    Route::Home,
+    Route::Skills,
+    Route::Experience,
+    Route::Education,
+    Route::Achievements,
+    Route::Creations,
+    Route::Projects,
+    Route::Articles,
+    Route::Contact,
+    Route::Cv,
+];
+
+/// The commit the site was built from, set by `build.rs`.
+pub const BUILD_COMMIT: &str = env!("KIRA_BUILD_COMMIT");
+
+/// The date the site was built, in ISO 8601 format, set by `build.rs`.
+pub const BUILD_DATE: &str = env!("KIRA_BUILD_DATE");
+
+/// The year the site was built, set by `build.rs`.
+pub const BUILD_YEAR: &str = env!("KIRA_BUILD_YEAR");
+
+#[derive(Properties, PartialEq)]
+pub struct LayoutProperties {
+    pub portfolio: Rc<Portfolio>,
+    pub children: Children,
+}
+
+/// The frame around every page: header, navigation, breadcrumbs and footer.
+///
+/// This must be rendered inside the router, so that its links work and the
+/// current route can be highlighted.
+#[function_component(Layout)]
+pub fn layout(
+    LayoutProperties {
+        portfolio,
+        children,
+    }: &LayoutProperties,
+) -> Html {
+    let messages = Locale::current().messages();
+
+    html! {
+        <>
+            <a class={"skip-link"} href={"#main"}>{messages.skip_to_content}</a>
+            <header id={"header"}>
+                <p class={"site-title"}>{messages.site_title}</p>
+                <img src={"/icon.png"} alt={messages.site_icon} />
+                <LocaleSwitcher />
+                <ThemeToggle />
+            </header>
+            <Navigation />
+            <main id={"main"} tabindex={"-1"}>
+                <Breadcrumbs portfolio={portfolio.clone()} />
+                {children.clone()}
+            </main>
+            <Footer contact={portfolio.contact.clone()} />
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SiteProperties {
+    pub portfolio: Rc<Portfolio>,
+}
+
+/// The whole site: the [`Layout`] around the page for the current route.
+///
+/// This must be rendered inside a router: a `BrowserRouter` in the app, or a
+/// `Router` over a `MemoryHistory` when rendering a route on its own.
+#[function_component(Site)]
+pub fn site(SiteProperties { portfolio }: &SiteProperties) -> Html {
+    let render = {
+        let portfolio = portfolio.clone();
+        move |route: Route| switch(route, &portfolio)
+    };
+
+    html! {
+        <Layout portfolio={portfolio.clone()}>
+            <Switch<Route> {render} />
+        </Layout>
+    }
+}
+
+/// The site navigation, highlighting the section of the current route.
+///
+/// On narrow viewports the entries collapse behind a menu button. While the
+/// menu is open, <kbd>Tab</kbd> cycles between the button and the entries,
+/// <kbd>Escape</kbd> closes it, and it closes by itself when the route changes.
+#[function_component(Navigation)]
+pub fn navigation() -> Html {
+    let route = use_route::<Route>();
+    // The route the menu was opened on, so that it is shown as closed as soon
+    // as the visitor navigates away, however they do so.
+    let opened_on = use_state(|| None::<Option<Route>>);
+    let toggle = use_node_ref();
+    let last_entry = use_node_ref();
+
+    let open = opened_on.as_ref() == Some(&route);
+    let section = route.as_ref().map(Route::section);
+    let messages = Locale::current().messages();
+
+    let onclick = {
+        let opened_on = opened_on.clone();
+        Callback::from(move |_: MouseEvent| {
+            opened_on.set((!open).then(|| route.clone()));
+        })
+    };
+
+    let onkeydown = {
+        let toggle = toggle.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            if open && event.key() == "Escape" {
+                opened_on.set(None);
+                focus(&toggle);
+            }
+        })
+    };
+
+    // Keeps focus inside the open menu by wrapping from either end to the other.
+    let trap_focus = |from_last: bool, to: NodeRef| {
+        Callback::from(move |event: KeyboardEvent| {
+            if open && event.key() == "Tab" && event.shift_key() != from_last {
+                event.prevent_default();
+                focus(&to);
+            }
+        })
+    };
+
+    html! {
+        <nav id={"nav-bar"} aria-label={messages.main_navigation} {onkeydown}>
+            <button
+                class={"nav-toggle"}
+                ref={toggle.clone()}
+                aria-controls={"nav"}
+                aria-expanded={open.to_string()}
+                aria-label={if open { messages.close_menu } else { messages.open_menu }}
+                {onclick}
+                onkeydown={trap_focus(false, last_entry.clone())}
+            >
+                <i
+                    class={if open { "fa-solid fa-xmark" } else { "fa-solid fa-bars" }}
+                    aria-hidden={"true"}
+                ></i>
+            </button>
+            <ul id={"nav"} class={classes!(open.then_some("open"))}>
+                {NAV_ENTRIES.into_iter().enumerate().map(|(index, entry)| {
+                    let active = section.as_ref() == Some(&entry);
+                    let is_last = index == NAV_ENTRIES.len() - 1;
+
+                    html! {
+                        <li onkeydown={is_last.then(|| trap_focus(true, toggle.clone()))}>
+                            <Link<Route>
+                                to={entry.clone()}
+                                classes={classes!(active.then_some("active"))}
+                                anchor_ref={if is_last { last_entry.clone() } else { NodeRef::default() }}
+                            >
+                                {entry.title()}
+                            </Link<Route>>
+                        </li>
+                    }
+                }).collect::<Html>()}
+            </ul>
+        </nav>
+    }
+}
+
+/// Moves keyboard focus to the referenced element, if it is rendered.
+pub(crate) fn focus(node: &NodeRef) {
+    if let Some(element) = node.cast::<web_sys::HtmlElement>() {
+        let _ = element.focus();
+    }
+}
+
+#[derive(Properties, PartialEq, Eq)]
+pub struct FooterProperties {
+    pub contact: ContactDetails,
+}
+
+/// The site-wide footer, with contact links, the owner's structured data, the
+/// licence and when the site was last built.
+#[function_component(Footer)]
+pub fn footer(FooterProperties { contact }: &FooterProperties) -> Html {
+    let messages = Locale::current().messages();
+
+    html! {
+        <footer id={"footer"}>
+            <div class={"footer-links"}>
+                {contact.icons_to_html("footer-icon")}
+            </div>
+            <p class={"footer-detail"}>
+                {(messages.copyright)(BUILD_YEAR, &contact.full_name())}
+                {messages.licensed_under}
+                <a href={"https://www.gnu.org/licenses/gpl-3.0"}>{"GNU GPL v3.0"}</a>
+                {"."}
+            </p>
+            <p class={"footer-detail"}>
+                {messages.last_built}
+                <time datetime={BUILD_DATE}>{BUILD_DATE}</time>
+                {(messages.built_from)(BUILD_COMMIT)}
+            </p>
+            <script type={"application/ld+json"}>{contact.to_json_ld().to_string()}</script>
+        </footer>
+    }
+}
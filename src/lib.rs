@@ -0,0 +1,52 @@
+#![allow(clippy::must_use_candidate)]
+
+pub mod breadcrumbs;
+pub mod check;
+pub mod contact;
+pub mod contact_form;
+pub mod content;
+pub mod data;
+pub mod date_format;
+pub mod diagnostics;
+pub mod i18n;
+pub mod id;
+pub mod images;
+pub mod layout;
+pub mod lookup;
+pub mod media;
+pub mod pages;
+pub mod resume;
+pub mod scaffold;
+pub mod theme;
+pub mod typing;
+
+#[allow(clippy::wildcard_imports)]
+pub use breadcrumbs::*;
+#[allow(clippy::wildcard_imports)]
+pub use contact::*;
+#[allow(clippy::wildcard_imports)]
+pub use contact_form::*;
+#[allow(clippy::wildcard_imports)]
+pub use content::*;
+#[allow(clippy::wildcard_imports)]
+pub use date_format::*;
+#[allow(clippy::wildcard_imports)]
+pub use diagnostics::*;
+#[allow(clippy::wildcard_imports)]
+pub use i18n::*;
+#[allow(clippy::wildcard_imports)]
+pub use id::*;
+#[allow(clippy::wildcard_imports)]
+pub use images::*;
+#[allow(clippy::wildcard_imports)]
+pub use layout::*;
+#[allow(clippy::wildcard_imports)]
+pub use lookup::*;
+#[allow(clippy::wildcard_imports)]
+pub use media::*;
+#[allow(clippy::wildcard_imports)]
+pub use pages::*;
+#[allow(clippy::wildcard_imports)]
+pub use theme::*;
+#[allow(clippy::wildcard_imports)]
+pub use typing::*;
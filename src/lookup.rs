@@ -0,0 +1,132 @@
+//! Hash indexes for finding content items without scanning their list.
+//!
+//! Each list builds a [`Lookup`] of its items when it's constructed, mapping
+//! their names, case-folded names, [`slug`]s and [`Id`]s to their positions.
+//! Like the linear searches they replace, lookups find the first item when
+//! several share a key; `kira-content check` reports any that do.
+
+use crate::Id;
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Skill;
+    use rstest::rstest;
+
+    fn lookup() -> Lookup<Skill> {
+        Lookup::new(["Rust", "Web Development", "C++", "rust"])
+    }
+
+    #[rstest]
+    #[case("Web Profile", "web-profile")]
+    #[case("  C++ & Rust!  ", "c-rust")]
+    #[case("Café_Menu 2", "caf-menu-2")]
+    #[case("<NAME>", "name")]
+    fn slug_works_as_expected(#[case] name: &str, #[case] expected: &str) {
+        assert_eq!(slug(name), expected);
+    }
+
+    #[rstest]
+    #[case("Rust", Some(0))]
+    #[case("rust", Some(3))]
+    #[case("RUST", None)]
+    #[case("Web Development", Some(1))]
+    #[case("Python", None)]
+    fn lookup_name_works_as_expected(#[case] name: &str, #[case] expected: Option<usize>) {
+        assert_eq!(lookup().name(name), expected);
+    }
+
+    #[rstest]
+    #[case("RUST", Some(0))]
+    #[case("web development", Some(1))]
+    #[case("Python", None)]
+    fn lookup_name_ignoring_case_works_as_expected(
+        #[case] name: &str,
+        #[case] expected: Option<usize>,
+    ) {
+        assert_eq!(lookup().name_ignoring_case(name), expected);
+    }
+
+    #[rstest]
+    #[case("web-development", Some(1))]
+    #[case("c", Some(2))]
+    #[case("rust", Some(0))]
+    #[case("Web Development", None)]
+    fn lookup_slug_works_as_expected(#[case] slug: &str, #[case] expected: Option<usize>) {
+        assert_eq!(lookup().slug(slug), expected);
+    }
+
+    #[rstest]
+    fn lookup_id_works_as_expected() {
+        assert_eq!(lookup().id(Id::from_name("C++")), Some(2));
+        assert_eq!(lookup().id(Id::from_name("rust")), Some(3));
+        assert_eq!(lookup().id(Id::from_name("Python")), None);
+    }
+}
+
+/// Returns the URL-friendly form of a name, like `web-profile` for "Web
+/// Profile": lowercase ASCII letters and digits, with every other run of
+/// characters replaced by a single `-`.
+pub fn slug(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<String>>()
+        .join("-")
+}
+
+/// The positions of a list's items of type `T`, keyed in each of the ways
+/// they can be looked up.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Lookup<T> {
+    names: HashMap<String, usize>,
+    folded_names: HashMap<String, usize>,
+    slugs: HashMap<String, usize>,
+    ids: HashMap<Id<T>, usize>,
+}
+
+impl<T> Lookup<T> {
+    /// Returns the index of the items with the given names, in list order.
+    pub fn new<'a>(names: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut lookup = Self {
+            names: HashMap::new(),
+            folded_names: HashMap::new(),
+            slugs: HashMap::new(),
+            ids: HashMap::new(),
+        };
+
+        for (index, name) in names.into_iter().enumerate() {
+            lookup.names.entry(name.to_string()).or_insert(index);
+            lookup
+                .folded_names
+                .entry(name.to_lowercase())
+                .or_insert(index);
+            lookup.slugs.entry(slug(name)).or_insert(index);
+            lookup.ids.entry(Id::from_name(name)).or_insert(index);
+        }
+
+        lookup
+    }
+
+    /// Returns the position of the first item with exactly the given name.
+    pub fn name(&self, name: &str) -> Option<usize> {
+        self.names.get(name).copied()
+    }
+
+    /// Returns the position of the first item with the given name, ignoring
+    /// case, so that "rust" finds "Rust".
+    pub fn name_ignoring_case(&self, name: &str) -> Option<usize> {
+        self.folded_names.get(&name.to_lowercase()).copied()
+    }
+
+    /// Returns the position of the first item with the given [`slug`].
+    pub fn slug(&self, slug: &str) -> Option<usize> {
+        self.slugs.get(slug).copied()
+    }
+
+    /// Returns the position of the item with the given id.
+    pub fn id(&self, id: Id<T>) -> Option<usize> {
+        self.ids.get(&id).copied()
+    }
+}
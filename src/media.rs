@@ -0,0 +1,389 @@
+//! Images, videos and audio attached to content items.
+//!
+//! Media files live in the `media/` directory, which Trunk copies into the
+//! site as-is, so a file at `media/render.png` has the `src` `/media/render.png`.
+//!
+//! On a content item's page, its media is shown in a [`Gallery`]. Images open
+//! in a lightbox, which is navigated with the arrow keys and closed with
+//! <kbd>Escape</kbd>; videos and audio play in place. Cards show the item's
+//! first image as a [`thumbnail`].
+//!
+//! Images are shown as a [`picture`] of their responsive variants, so the
+//! `sizes` here should follow `style.css`.
+
+use crate::{layout::focus, picture, Locale, Localised, ToHtml};
+use yew::prelude::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn media() -> Vec<Media> {
+        vec![
+            Media::audio("/media/theme.ogg", Localised::from("Theme")),
+            Media::image("/media/render.png", Localised::from("Render")),
+            Media::video("/media/walkthrough.webm", Localised::from("Walkthrough")),
+            Media::image("/media/wireframe.png", Localised::from("Wireframe")),
+        ]
+    }
+
+    #[rstest]
+    #[case(1, true, Some(3))]
+    #[case(3, true, Some(1))]
+    #[case(1, false, Some(3))]
+    #[case(3, false, Some(1))]
+    #[case(0, true, None)]
+    #[case(2, true, None)]
+    #[case(9, true, None)]
+    fn step_works_as_expected(
+        #[case] current: usize,
+        #[case] forwards: bool,
+        #[case] expected: Option<usize>,
+    ) {
+        assert_eq!(step(&media(), current, forwards), expected);
+    }
+
+    #[rstest]
+    fn step_stays_on_a_lone_image() {
+        let media = vec![Media::image("/media/render.png", Localised::from("Render"))];
+
+        assert_eq!(step(&media, 0, true), Some(0));
+    }
+
+    #[rstest]
+    fn first_image_skips_other_media() {
+        assert_eq!(
+            first_image(&media()).map(|image| image.src.as_str()),
+            Some("/media/render.png")
+        );
+        assert_eq!(first_image(&media()[2..3]), None);
+    }
+}
+
+/// What a [`Media`] file is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaKind {
+    Image,
+    Video,
+    Audio,
+}
+
+/// A media file attached to a content item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Media {
+    pub kind: MediaKind,
+    /// The file's address, relative to the site's root, like
+    /// `/media/render.png`.
+    pub src: String,
+    /// A description of the file for those who can't see or hear it.
+    pub alt: Localised,
+    pub caption: Option<Localised>,
+}
+
+impl Media {
+    /// Returns an image, without a caption.
+    pub fn image(src: &str, alt: Localised) -> Self {
+        Self::new(MediaKind::Image, src, alt)
+    }
+
+    /// Returns a video, without a caption.
+    pub fn video(src: &str, alt: Localised) -> Self {
+        Self::new(MediaKind::Video, src, alt)
+    }
+
+    /// Returns an audio file, without a caption.
+    pub fn audio(src: &str, alt: Localised) -> Self {
+        Self::new(MediaKind::Audio, src, alt)
+    }
+
+    fn new(kind: MediaKind, src: &str, alt: Localised) -> Self {
+        Self {
+            kind,
+            src: src.to_string(),
+            alt,
+            caption: None,
+        }
+    }
+
+    /// Adds a caption, shown beneath the file.
+    #[must_use]
+    pub fn with_caption(mut self, caption: Localised) -> Self {
+        self.caption = Some(caption);
+        self
+    }
+
+    /// Returns the caption as a `<figcaption>`, or nothing if there isn't one.
+    fn caption_to_html(&self) -> Html {
+        self.caption.as_ref().map_or_else(
+            || html! {},
+            |caption| html! { <figcaption>{caption.to_string()}</figcaption> },
+        )
+    }
+}
+
+impl ToHtml for Media {
+    /// Returns the file as an image sized for a gallery, or as a player for
+    /// videos and audio.
+    fn to_html(&self) -> Html {
+        let alt = self.alt.to_string();
+
+        match self.kind {
+            MediaKind::Image => {
+                picture(&self.src, alt, "(max-width: 30em) 100vw, 20em", classes!())
+            }
+            MediaKind::Video => html! {
+                <video src={self.src.clone()} controls={true} preload={"metadata"} aria-label={alt}></video>
+            },
+            MediaKind::Audio => html! {
+                <audio src={self.src.clone()} controls={true} preload={"metadata"} aria-label={alt}></audio>
+            },
+        }
+    }
+}
+
+/// Returns the first image, which represents the item on its card.
+pub fn first_image(media: &[Media]) -> Option<&Media> {
+    media.iter().find(|media| media.kind == MediaKind::Image)
+}
+
+/// Returns the item's first image as a card thumbnail, or nothing if it has
+/// no images.
+pub fn thumbnail(media: &[Media]) -> Html {
+    first_image(media).map_or_else(
+        || html! {},
+        |image| {
+            picture(
+                &image.src,
+                image.alt.to_string(),
+                "(max-width: 50em) 100vw, 50em",
+                classes!("card-thumbnail"),
+            )
+        },
+    )
+}
+
+/// Returns the position of the next image after `current`, or the previous one
+/// if not `forwards`, wrapping around at either end. Returns `None` if
+/// `current` isn't an image.
+fn step(media: &[Media], current: usize, forwards: bool) -> Option<usize> {
+    let images = media
+        .iter()
+        .enumerate()
+        .filter(|(_, media)| media.kind == MediaKind::Image)
+        .map(|(index, _)| index)
+        .collect::<Vec<usize>>();
+    let position = images.iter().position(|index| *index == current)?;
+    let next = if forwards {
+        (position + 1) % images.len()
+    } else {
+        (position + images.len() - 1) % images.len()
+    };
+
+    images.get(next).copied()
+}
+
+#[derive(Properties, PartialEq, Eq)]
+pub struct GalleryProperties {
+    pub media: Vec<Media>,
+}
+
+/// A content item's media, with a lightbox for viewing its images full size.
+///
+/// While the lightbox is open, <kbd>Tab</kbd> cycles between its buttons, the
+/// arrow keys move between images, and <kbd>Escape</kbd> closes it, returning
+/// focus to the image it was opened from.
+///
+/// The gallery's state is built from the first media it's given. Give it a
+/// `key` that changes with the item, so that moving between items' pages
+/// starts a new gallery rather than reusing the last one.
+#[function_component(Gallery)]
+pub fn gallery(GalleryProperties { media }: &GalleryProperties) -> Html {
+    // The position of the image open in the lightbox.
+    let open = use_state(|| None::<usize>);
+    let openers = use_state(|| {
+        media
+            .iter()
+            .map(|_| NodeRef::default())
+            .collect::<Vec<NodeRef>>()
+    });
+    let close_button = use_node_ref();
+    let next_button = use_node_ref();
+    // Where focus should move once the lightbox has opened or closed.
+    let pending_focus = use_mut_ref(|| None::<NodeRef>);
+
+    {
+        let pending_focus = pending_focus.clone();
+        use_effect(move || {
+            if let Some(node) = pending_focus.borrow_mut().take() {
+                focus(&node);
+            }
+        });
+    }
+
+    if media.is_empty() {
+        return html! {};
+    }
+
+    let messages = Locale::current().messages();
+    let image_count = media
+        .iter()
+        .filter(|media| media.kind == MediaKind::Image)
+        .count();
+
+    let show = |index: usize| {
+        let open = open.clone();
+        let pending_focus = pending_focus.clone();
+        let close_button = close_button.clone();
+        Callback::from(move |_: MouseEvent| {
+            open.set(Some(index));
+            *pending_focus.borrow_mut() = Some(close_button.clone());
+        })
+    };
+
+    let close = {
+        let open = open.clone();
+        let openers = openers.clone();
+        let pending_focus = pending_focus.clone();
+        move || {
+            *pending_focus.borrow_mut() = open.and_then(|index| openers.get(index)).cloned();
+            open.set(None);
+        }
+    };
+
+    let move_to = {
+        let open = open.clone();
+        let media = media.clone();
+        move |forwards: bool| {
+            if let Some(next) = open.and_then(|current| step(&media, current, forwards)) {
+                open.set(Some(next));
+            }
+        }
+    };
+
+    let onkeydown = {
+        let close = close.clone();
+        let move_to = move_to.clone();
+        Callback::from(move |event: KeyboardEvent| match event.key().as_str() {
+            "Escape" => close(),
+            "ArrowLeft" => move_to(false),
+            "ArrowRight" => move_to(true),
+            _ => {}
+        })
+    };
+
+    // Keeps focus inside the lightbox by wrapping from either end to the other.
+    // With a single image, the close button is both ends.
+    let trap_from_close = {
+        let close_button = close_button.clone();
+        let next_button = next_button.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            if event.key() != "Tab" {
+                return;
+            }
+
+            if image_count == 1 {
+                event.prevent_default();
+                focus(&close_button);
+            } else if event.shift_key() {
+                event.prevent_default();
+                focus(&next_button);
+            }
+        })
+    };
+    let trap_from_next = {
+        let close_button = close_button.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            if event.key() == "Tab" && !event.shift_key() {
+                event.prevent_default();
+                focus(&close_button);
+            }
+        })
+    };
+
+    let open_image = open
+        .and_then(|index| media.get(index))
+        .filter(|media| media.kind == MediaKind::Image);
+    let lightbox = open_image.map_or_else(
+        || html! {},
+        |image| {
+            let onclose = {
+                let close = close.clone();
+                Callback::from(move |_: MouseEvent| close())
+            };
+            let onprevious = {
+                let move_to = move_to.clone();
+                Callback::from(move |_: MouseEvent| move_to(false))
+            };
+            let onnext = {
+                let move_to = move_to.clone();
+                Callback::from(move |_: MouseEvent| move_to(true))
+            };
+
+            html! {
+                <div class={"lightbox"} role={"dialog"} aria-modal={"true"} aria-label={messages.gallery} {onkeydown}>
+                    <div class={"lightbox-backdrop"} onclick={onclose.clone()}></div>
+                    <figure class={"lightbox-content"}>
+                        {picture(&image.src, image.alt.to_string(), "90vw", classes!())}
+                        {image.caption_to_html()}
+                    </figure>
+                    <button
+                        class={"lightbox-close"}
+                        ref={close_button.clone()}
+                        aria-label={messages.close}
+                        onclick={onclose}
+                        onkeydown={trap_from_close}
+                    >
+                        <i class={"fa-solid fa-xmark"} aria-hidden={"true"}></i>
+                    </button>
+                    if image_count > 1 {
+                        <button
+                            class={"lightbox-previous"}
+                            aria-label={messages.previous_image}
+                            onclick={onprevious}
+                        >
+                            <i class={"fa-solid fa-chevron-left"} aria-hidden={"true"}></i>
+                        </button>
+                        <button
+                            class={"lightbox-next"}
+                            ref={next_button.clone()}
+                            aria-label={messages.next_image}
+                            onclick={onnext}
+                            onkeydown={trap_from_next}
+                        >
+                            <i class={"fa-solid fa-chevron-right"} aria-hidden={"true"}></i>
+                        </button>
+                    }
+                </div>
+            }
+        },
+    );
+
+    html! {
+        <section class={"gallery"}>
+            <h2>{messages.gallery}</h2>
+            <ul class={"gallery-grid"}>{
+                media.iter().enumerate().map(|(index, item)| html! {
+                    <li key={index}>
+                        <figure class={"gallery-item"}>
+                            if item.kind == MediaKind::Image {
+                                <button
+                                    class={"gallery-open"}
+                                    ref={openers.get(index).cloned().unwrap_or_default()}
+                                    aria-label={(messages.view_image)(&item.alt.to_string())}
+                                    onclick={show(index)}
+                                >
+                                    {item.to_html()}
+                                </button>
+                            } else {
+                                {item.to_html()}
+                            }
+                            {item.caption_to_html()}
+                        </figure>
+                    </li>
+                }).collect::<Html>()
+            }</ul>
+            {lightbox}
+        </section>
+    }
+}
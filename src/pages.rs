@@ -1,7 +1,16 @@
+use crate::contact::{ContactDetails, ContactProperties};
+use crate::contact_form::ContactForm;
 use crate::content::{
-    AchievementListProperties, ArticleListProperties, CreationListProperties, SkillListProperties,
-    ToHtml,
+    date_range, AchievementList, AchievementListProperties, Area, AreaList, ArticleListProperties,
+    CompetencyChart, Content, ContentComponent, ContentItem, ContentOrder, CreationList,
+    CreationListProperties, EducationList, EducationListProperties, ExperienceList,
+    ExperienceListProperties, Get, ProjectList, ProjectListProperties, SkillList, SkillOrder,
+    SkillUsages, ToHtml,
 };
+use crate::data::{contact_backend, contact_details, Portfolio};
+use crate::date_format::FormattedDate;
+use crate::i18n::Locale;
+use std::{collections::HashSet, rc::Rc};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
@@ -21,23 +30,151 @@ pub enum Route {
     Creations,
     #[at("/creations/:creation")]
     Creation { creation: String },
+    #[at("/projects")]
+    Projects,
+    #[at("/projects/:project")]
+    Project { project: String },
     #[at("/articles")]
     Articles,
     #[at("/articles/:article")]
     Article { article: String },
+    #[at("/education")]
+    Education,
+    #[at("/education/:qualification")]
+    Qualification { qualification: String },
+    #[at("/experience")]
+    Experience,
+    #[at("/experience/:role")]
+    Role { role: String },
+    #[at("/areas/*area")]
+    Area { area: String },
     #[at("/contact")]
     Contact,
+    #[at("/cv")]
+    Cv,
     #[not_found]
     #[at("/404")]
     NotFound,
 }
 
-#[function_component(Home)]
-pub fn home() -> Html {
-    html! {
-        <div>
-            <h1>{"Home"}</h1>
+impl Route {
+    /// Returns the top-level route the route belongs to, like
+    /// [`Route::Skills`] for a [`Route::Skill`].
+    #[must_use]
+    pub fn section(&self) -> Self {
+        match self {
+            Self::Skill { .. } => Self::Skills,
+            Self::Achievement { .. } => Self::Achievements,
+            Self::Creation { .. } => Self::Creations,
+            Self::Project { .. } => Self::Projects,
+            Self::Article { .. } => Self::Articles,
+            Self::Qualification { .. } => Self::Education,
+            Self::Role { .. } => Self::Experience,
+            Self::Area { .. } => Self::Home,
+            route => route.clone(),
+        }
+    }
+
+    /// Returns the route's title in the current locale, as used in
+    /// navigation.
+    pub fn title(&self) -> String {
+        Locale::current().messages().route_title(self)
+    }
+}
 
+/// Returns the page for `route`, built from the portfolio's content.
+pub fn switch(route: Route, portfolio: &Portfolio) -> Html {
+    let Portfolio {
+        contact,
+        areas,
+        skills,
+        achievements,
+        creations,
+        projects,
+        articles,
+        education,
+        experience,
+        usage,
+        ..
+    } = portfolio;
+
+    match route {
+        Route::Home => html! { <Home /> },
+        Route::Skills => html! { <Skills skills={skills.clone()} usage={usage.clone()} /> },
+        Route::Skill { skill } => html! {
+            <ContentComponent
+                content={skills.get(&skill).map_or_else(|| Content::NotFound, |skill| {
+                    let usage = usage.get(&skill);
+                    Content::Skill(skill, usage)
+                })} />
+        },
+        Route::Achievements => html! { <Achievements achievements={achievements.clone()} /> },
+        Route::Achievement { achievement } => html! {
+            <ContentComponent
+                content={achievements.get(&achievement).map_or_else(|| Content::NotFound, Content::Achievement)} />
+        },
+        Route::Creations => html! { <Creations creations={creations.clone()} /> },
+        Route::Creation { creation } => html! {
+            <ContentComponent
+                content={creations.get(&creation).map_or_else(|| Content::NotFound, Content::Creation)} />
+        },
+        Route::Projects => html! { <Projects projects={projects.clone()} /> },
+        Route::Project { project } => html! {
+            <ContentComponent
+                content={projects.get(&project).map_or_else(|| Content::NotFound, Content::Project)} />
+        },
+        Route::Articles => html! { <Articles articles={articles.clone()} /> },
+        Route::Article { article } => html! {
+            <ContentComponent
+                content={articles.get(&article).map_or_else(|| Content::NotFound, Content::Article)} />
+        },
+        Route::Education => html! { <EducationTimeline education={education.clone()} /> },
+        Route::Qualification { qualification } => html! {
+            <ContentComponent
+                content={education.get(&qualification).map_or_else(|| Content::NotFound, Content::Education)} />
+        },
+        Route::Experience => html! { <ExperienceTimeline experience={experience.clone()} /> },
+        Route::Role { role } => html! {
+            <ContentComponent
+                content={experience.get(&role).map_or_else(|| Content::NotFound, Content::Experience)} />
+        },
+        Route::Area { area } => areas.get(&area).map_or_else(
+            || html! { <NotFound /> },
+            |area| {
+                html! {
+                    <AreaOverview
+                        {area}
+                        areas={areas.clone()}
+                        skills={skills.clone()}
+                        achievements={achievements.clone()}
+                        creations={creations.clone()}
+                        projects={projects.clone()}
+                        education={education.clone()}
+                        experience={experience.clone()} />
+                }
+            },
+        ),
+        Route::Contact => html! { <Contact details={contact.clone()} /> },
+        Route::Cv => html! {
+            <Cv
+                contact={contact.clone()}
+                areas={areas.clone()}
+                skills={skills.clone()}
+                experience={experience.clone()}
+                education={education.clone()}
+                achievements={achievements.clone()}
+                creations={creations.clone()}
+                projects={projects.clone()} />
+        },
+        Route::NotFound => html! { <NotFound /> },
+    }
+}
+
+/// The short biography shared by the home page and the CV.
+#[function_component(Bio)]
+pub fn bio() -> Html {
+    html! {
+        <>
             <p>{
                 "Hi, I'm Kira H, and I somehow exist, unfortunately for you."
             }</p>
@@ -48,21 +185,73 @@ pub fn home() -> Html {
                 {"I was born in the small town of Wincanton in rural England. "}
                 {"It was a Wednesday, Wednesday the 14th of September, in 2005. "}
             </p>
+        </>
+    }
+}
+
+#[function_component(Home)]
+pub fn home() -> Html {
+    html! {
+        <div>
+            <h1>{Locale::current().messages().home}</h1>
+
+            <Bio />
         </div>
     }
 }
 
+#[derive(Properties, PartialEq, Eq)]
+pub struct SkillsProperties {
+    pub skills: SkillList,
+    pub usage: SkillUsages,
+}
+
 #[function_component(Skills)]
-pub fn skills(SkillListProperties { skills }: &SkillListProperties) -> Html {
+pub fn skills(SkillsProperties { skills, usage }: &SkillsProperties) -> Html {
+    let order = use_state(|| SkillOrder::Declared);
+    let grouped = use_state(|| false);
+
+    let toggle_grouped = {
+        let grouped = grouped.clone();
+        Callback::from(move |_| grouped.set(!*grouped))
+    };
+    let messages = Locale::current().messages();
+
     html! {
         <div>
-            <h1>{"Skills"}</h1>
+            <h1>{messages.skills}</h1>
+
+            <p>{messages.skills_intro}</p>
+
+            <hr />
 
-            <p>{"These are my skills."}</p>
+            <CompetencyChart skills={skills.clone()} />
 
             <hr />
 
-            <div>{skills.to_html()}</div>
+            <div class={"list-controls"}>
+                <span>{messages.sort_by}</span>
+                {SkillOrder::ALL.into_iter().map(|option| {
+                    let order = order.clone();
+                    let active = *order == option;
+
+                    html! {
+                        <button
+                            class={classes!("list-control", active.then_some("active"))}
+                            onclick={Callback::from(move |_| order.set(option))}
+                        >
+                            {option.to_string()}
+                        </button>
+                    }
+                }).collect::<Html>()}
+
+                <label>
+                    <input type={"checkbox"} checked={*grouped} onclick={toggle_grouped} />
+                    {messages.group_by_category}
+                </label>
+            </div>
+
+            <div>{skills.to_html_arranged(*order, *grouped, usage)}</div>
         </div>
     }
 }
@@ -71,11 +260,13 @@ pub fn skills(SkillListProperties { skills }: &SkillListProperties) -> Html {
 pub fn achievements(
     AchievementListProperties { achievements }: &AchievementListProperties,
 ) -> Html {
+    let messages = Locale::current().messages();
+
     html! {
         <div>
-            <h1>{"Achievements"}</h1>
+            <h1>{messages.achievements}</h1>
 
-            <p>{"These are my achievements."}</p>
+            <p>{messages.achievements_intro}</p>
 
             <hr />
 
@@ -86,11 +277,13 @@ pub fn achievements(
 
 #[function_component(Creations)]
 pub fn creations(CreationListProperties { creations }: &CreationListProperties) -> Html {
+    let messages = Locale::current().messages();
+
     html! {
         <div>
-            <h1>{"Creations"}</h1>
+            <h1>{messages.creations}</h1>
 
-            <p>{"These are my creations."}</p>
+            <p>{messages.creations_intro}</p>
 
             <hr />
 
@@ -99,13 +292,32 @@ pub fn creations(CreationListProperties { creations }: &CreationListProperties)
     }
 }
 
+#[function_component(Projects)]
+pub fn projects(ProjectListProperties { projects }: &ProjectListProperties) -> Html {
+    let messages = Locale::current().messages();
+
+    html! {
+        <div>
+            <h1>{messages.projects}</h1>
+
+            <p>{messages.projects_intro}</p>
+
+            <hr />
+
+            <div>{projects.to_html()}</div>
+        </div>
+    }
+}
+
 #[function_component(Articles)]
 pub fn articles(ArticleListProperties { articles }: &ArticleListProperties) -> Html {
+    let messages = Locale::current().messages();
+
     html! {
         <div>
-            <h1>{"Articles"}</h1>
+            <h1>{messages.articles}</h1>
 
-            <p>{"These are my articles."}</p>
+            <p>{messages.articles_intro}</p>
 
             <hr />
 
@@ -114,94 +326,443 @@ pub fn articles(ArticleListProperties { articles }: &ArticleListProperties) -> H
     }
 }
 
+#[function_component(EducationTimeline)]
+pub fn education_timeline(EducationListProperties { education }: &EducationListProperties) -> Html {
+    let messages = Locale::current().messages();
+
+    html! {
+        <div>
+            <h1>{messages.education}</h1>
+
+            <p>{messages.education_intro}</p>
+
+            <hr />
+
+            {education.timeline_to_html()}
+        </div>
+    }
+}
+
+#[function_component(ExperienceTimeline)]
+pub fn experience_timeline(
+    ExperienceListProperties { experience }: &ExperienceListProperties,
+) -> Html {
+    let messages = Locale::current().messages();
+
+    html! {
+        <div>
+            <h1>{messages.experiences}</h1>
+
+            <p>{messages.experience_intro}</p>
+
+            <hr />
+
+            {experience.timeline_to_html()}
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq, Eq)]
+pub struct AreaOverviewProperties {
+    pub area: Area,
+    pub areas: AreaList,
+    pub skills: SkillList,
+    pub achievements: AchievementList,
+    pub creations: CreationList,
+    pub projects: ProjectList,
+    pub education: EducationList,
+    pub experience: ExperienceList,
+}
+
+/// Returns the cards of `items` under a heading, or `None` if there are none.
+fn area_section<T: ContentItem>(title: &'static str, items: &[&T]) -> Option<Html> {
+    (!items.is_empty()).then(|| {
+        html! {
+            <section>
+                <h2>{title}</h2>
+                {items.iter().map(|item| item.card_to_html(3)).collect::<Html>()}
+            </section>
+        }
+    })
+}
+
+/// The content in an area and its sub-areas, with links to the sub-areas.
+#[function_component(AreaOverview)]
+pub fn area_overview(
+    AreaOverviewProperties {
+        area,
+        areas,
+        skills,
+        achievements,
+        creations,
+        projects,
+        education,
+        experience,
+    }: &AreaOverviewProperties,
+) -> Html {
+    let messages = Locale::current().messages();
+    let sub_areas = areas
+        .iter()
+        .filter(|other| other.parent() == Some(*area))
+        .collect::<Vec<&Area>>();
+    let sections = [
+        area_section(messages.skills, &skills.within_area(*area)),
+        area_section(messages.experiences, &experience.within_area(*area)),
+        area_section(messages.education, &education.within_area(*area)),
+        area_section(messages.achievements, &achievements.within_area(*area)),
+        area_section(messages.creations, &creations.within_area(*area)),
+        area_section(messages.projects, &projects.within_area(*area)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<Html>>();
+
+    html! {
+        <div>
+            <h1>{area.to_string()}</h1>
+
+            if !sub_areas.is_empty() {
+                <p class={"card-detail"}>
+                    {messages.sub_areas}
+                    {sub_areas.iter().map(|area| area.to_html()).collect::<Html>()}
+                </p>
+            }
+
+            <hr />
+
+            if sections.is_empty() {
+                <p>{messages.nothing_in_area}</p>
+            } else {
+                {sections.into_iter().collect::<Html>()}
+            }
+        </div>
+    }
+}
+
 #[function_component(Contact)]
-pub fn contact() -> Html {
+pub fn contact(ContactProperties { details }: &ContactProperties) -> Html {
+    let backend = use_state(|| contact_backend(details));
+    let messages = Locale::current().messages();
+
     html! {
         <div>
-            <h1>{"Contact"}</h1>
+            <h1>{messages.contact}</h1>
 
-            <p>{
-                "If you have any questions, feel free to contact me."
-            }</p>
+            <p>{messages.contact_intro}</p>
 
-            <div class={"contact-details-container"}>
-                <a
-                    class={"contact-icon"}
-                    href={"mailto:kira.hudson.v0@gmail.com"}
-                    title={"kira.hudson.v0@gmail.com"}
-                    id={"Email"}
-                    target={"_blank"}
-                >
-                    <i class={"fa-solid fa-square-envelope"}></i>
-                </a>
-
-                <a
-                    class={"contact-icon"}
-                    href={"https://github.com/QueenKiraThePansexualPixie/"}
-                    id={"GitHub"}
-                    title={"@QueenKiraThePansexualPixie"}
-                    target={"_blank"}
-                >
-                    <i class={"fa-brands fa-square-github"}></i>
-                </a>
-
-                <a
-                    class={"contact-icon"}
-                    href={"https://www.tumblr.com/blog/kira-is-pan/"}
-                    id={"Tumblr"}
-                    title={"@kira-is-pan"}
-                    target={"_blank"}
-                >
-                    <i class={"fa-brands fa-square-tumblr"}></i>
-                </a>
-
-                <a
-                    class={"contact-icon"}
-                    href={"https://www.instagram.com/kirathepanpixie/"}
-                    id={"Instagram"}
-                    title={"@kirathepanpixie"}
-                    target={"_blank"}
-                >
-                    <i class={"fa-brands fa-square-instagram"}></i>
-                </a>
-
-                <a
-                    class={"contact-icon"}
-                    href={"https://www.pinterest.co.uk/kirathepansexualpixie/"}
-                    id={"Pinterest"}
-                    title={"@kirathepansexualpixie"}
-                    target={"_blank"}
-                >
-                    <i class={"fa-brands fa-square-pinterest"}></i>
-                </a>
-
-                <a
-                    class={"contact-icon"}
-                    href={"https://www.reddit.com/user/KiraThePanPixie/"}
-                    id={"Reddit"}
-                    title={"@KiraThePanPixie"}
-                    target={"_blank"}
-                >
-                    <i class={"fa-brands fa-square-reddit"}></i>
-                </a>
+            {details.to_html()}
+
+            <hr />
+
+            <h2>{messages.send_a_message}</h2>
+
+            <ContactForm backend={(*backend).clone()} />
+        </div>
+    }
+}
+
+/// The sections of the CV that can be toggled on and off.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CvSection {
+    Bio,
+    Skills,
+    Experience,
+    Education,
+    Achievements,
+    Creations,
+    Projects,
+}
+
+impl CvSection {
+    /// Every section, in the order they appear on the CV.
+    pub const ALL: [Self; 7] = [
+        Self::Bio,
+        Self::Skills,
+        Self::Experience,
+        Self::Education,
+        Self::Achievements,
+        Self::Creations,
+        Self::Projects,
+    ];
+}
+
+impl std::fmt::Display for CvSection {
+    /// Writes the section's heading in the current locale.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Locale::current().messages().cv_section(*self))
+    }
+}
+
+#[derive(Properties, PartialEq, Eq)]
+pub struct CvProperties {
+    pub contact: ContactDetails,
+    pub areas: AreaList,
+    pub skills: SkillList,
+    pub experience: ExperienceList,
+    pub education: EducationList,
+    pub achievements: AchievementList,
+    pub creations: CreationList,
+    pub projects: ProjectList,
+}
+
+/// Returns `true` if an item with the given areas should be shown, given the
+/// excluded areas. Items without any areas are always shown.
+fn cv_includes(areas: &[Rc<Area>], excluded: &HashSet<Area>) -> bool {
+    areas.is_empty() || areas.iter().any(|area| !excluded.contains(area.as_ref()))
+}
+
+/// A printable, one-page CV composed from the rest of the site's content.
+#[function_component(Cv)]
+pub fn cv(
+    CvProperties {
+        contact,
+        areas,
+        skills,
+        experience,
+        education,
+        achievements,
+        creations,
+        projects,
+    }: &CvProperties,
+) -> Html {
+    let hidden_sections = use_state(HashSet::<CvSection>::new);
+    let excluded_areas = use_state(HashSet::<Area>::new);
+
+    let shows = |section: CvSection| !hidden_sections.contains(&section);
+    let messages = Locale::current().messages();
+
+    html! {
+        <div>
+            <div class={"cv-controls"}>
+                <h1>{messages.cv}</h1>
+
+                <p>{messages.cv_intro}</p>
+
+                <fieldset>
+                    <legend>{messages.cv_sections}</legend>
+                    {CvSection::ALL.into_iter().map(|section| {
+                        let hidden_sections = hidden_sections.clone();
+                        let checked = !hidden_sections.contains(&section);
+                        let onclick = Callback::from(move |_| {
+                            let mut sections = (*hidden_sections).clone();
+                            if !sections.remove(&section) {
+                                sections.insert(section);
+                            }
+                            hidden_sections.set(sections);
+                        });
+
+                        html! {
+                            <label class={"cv-control"}>
+                                <input type={"checkbox"} {checked} {onclick} />
+                                {" "}{section.to_string()}
+                            </label>
+                        }
+                    }).collect::<Html>()}
+                </fieldset>
+
+                <fieldset>
+                    <legend>{messages.cv_areas}</legend>
+                    {areas.iter().copied().map(|area| {
+                        let excluded_areas = excluded_areas.clone();
+                        let checked = !excluded_areas.contains(&area);
+                        let onclick = Callback::from(move |_| {
+                            let mut areas = (*excluded_areas).clone();
+                            if !areas.remove(&area) {
+                                areas.insert(area);
+                            }
+                            excluded_areas.set(areas);
+                        });
+
+                        html! {
+                            <label class={"cv-control"}>
+                                <input type={"checkbox"} {checked} {onclick} />
+                                {" "}{area.to_string()}
+                            </label>
+                        }
+                    }).collect::<Html>()}
+                </fieldset>
+
+                <button class={"list-control"} onclick={Callback::from(|_| {
+                    if let Some(window) = web_sys::window() {
+                        let _ = window.print();
+                    }
+                })}>
+                    {messages.print}
+                </button>
+
+                <hr />
             </div>
+
+            <article class={"cv"}>
+                <h2 class={"cv-name"}>{contact.full_name()}</h2>
+
+                if shows(CvSection::Bio) {
+                    <section class={"cv-section"}>
+                        <h3>{CvSection::Bio.to_string()}</h3>
+                        <Bio />
+                    </section>
+                }
+
+                if shows(CvSection::Skills) {
+                    <section class={"cv-section"}>
+                        <h3>{CvSection::Skills.to_string()}</h3>
+                        {skills.by_competency().into_iter().map(|(competency, skills)| {
+                            let skills = skills
+                                .into_iter()
+                                .filter(|skill| cv_includes(&skill.areas, &excluded_areas))
+                                .map(|skill| skill.name.clone())
+                                .collect::<Vec<String>>();
+
+                            if skills.is_empty() {
+                                html! {}
+                            } else {
+                                html! {
+                                    <p class={"cv-item"}>
+                                        <b>{format!("{}: ", messages.competency_name(competency))}</b>
+                                        {skills.join(", ")}
+                                    </p>
+                                }
+                            }
+                        }).collect::<Html>()}
+                    </section>
+                }
+
+                if shows(CvSection::Experience) {
+                    <section class={"cv-section"}>
+                        <h3>{CvSection::Experience.to_string()}</h3>
+                        {experience
+                            .sorted(ContentOrder::Newest)
+                            .into_iter()
+                            .filter(|experience| cv_includes(&experience.areas, &excluded_areas))
+                            .map(|experience| html! {
+                                <p key={experience.id()} class={"cv-item"}>
+                                    <b>{experience.role.clone()}</b>
+                                    {format!(", {} (", experience.organisation)}
+                                    {date_range(experience.started, experience.ended)}
+                                    {format!(") - {}", experience.description)}
+                                </p>
+                            })
+                            .collect::<Html>()}
+                    </section>
+                }
+
+                if shows(CvSection::Education) {
+                    <section class={"cv-section"}>
+                        <h3>{CvSection::Education.to_string()}</h3>
+                        {education
+                            .sorted(ContentOrder::Newest)
+                            .into_iter()
+                            .filter(|education| cv_includes(&education.areas, &excluded_areas))
+                            .map(|education| html! {
+                                <p key={education.id()} class={"cv-item"}>
+                                    <b>{education.qualification.clone()}</b>
+                                    {format!(", {} (", education.institution)}
+                                    {date_range(education.started, education.finished)}
+                                    {format!(") - {}", education.description)}
+                                </p>
+                            })
+                            .collect::<Html>()}
+                    </section>
+                }
+
+                if shows(CvSection::Achievements) {
+                    <section class={"cv-section"}>
+                        <h3>{CvSection::Achievements.to_string()}</h3>
+                        {achievements
+                            .iter()
+                            .filter(|achievement| cv_includes(&achievement.areas, &excluded_areas))
+                            .map(|achievement| html! {
+                                <p key={achievement.id()} class={"cv-item"}>
+                                    <b>{achievement.name.clone()}</b>
+                                    {" ("}
+                                    <FormattedDate date={achievement.completed} />
+                                    {format!(") - {}", achievement.description)}
+                                </p>
+                            })
+                            .collect::<Html>()}
+                    </section>
+                }
+
+                if shows(CvSection::Creations) {
+                    <section class={"cv-section"}>
+                        <h3>{CvSection::Creations.to_string()}</h3>
+                        {creations
+                            .iter()
+                            .filter(|creation| cv_includes(&creation.areas, &excluded_areas))
+                            .map(|creation| html! {
+                                <p key={creation.id()} class={"cv-item"}>
+                                    <b>{creation.name.clone()}</b>
+                                    {" ("}
+                                    <FormattedDate date={creation.completed} />
+                                    {format!(") - {}", creation.description)}
+                                </p>
+                            })
+                            .collect::<Html>()}
+                    </section>
+                }
+
+                if shows(CvSection::Projects) {
+                    <section class={"cv-section"}>
+                        <h3>{CvSection::Projects.to_string()}</h3>
+                        {projects
+                            .iter()
+                            .filter(|project| cv_includes(&project.areas, &excluded_areas))
+                            .map(|project| html! {
+                                <p key={project.id()} class={"cv-item"}>
+                                    <b>{project.name.clone()}</b>
+                                    {" ("}
+                                    <FormattedDate date={project.started} />
+                                    {format!(", {}) - {}", project.status, project.description)}
+                                </p>
+                            })
+                            .collect::<Html>()}
+                    </section>
+                }
+            </article>
         </div>
     }
 }
 
 #[function_component(NotFound)]
 pub fn not_found() -> Html {
+    let locale = Locale::current();
+    let messages = locale.messages();
+
     html! {
         <div>
-            <h1>{"Error 404"}</h1>
+            <h1>{messages.error_404}</h1>
 
-            <p>{"Error 404 : Page Not Found"}</p>
+            <p>{messages.error_404_detail}</p>
 
-            <p>{"Please navigate your way back to the main site"}</p>
+            <p>{messages.error_404_help}</p>
 
             <p>
-                <b>{"EMERGENCY EXIT:"}</b>{" "}<a href={"/"}>{"Home"}</a>
+                <b>{messages.emergency_exit}</b>{" "}
+                <a href={locale.path_to(&Route::Home)}>{messages.home}</a>
             </p>
         </div>
     }
 }
+
+/// The page shown instead of the site when its content can't be loaded, as
+/// the router and navigation need the content to work. The owner's email
+/// address doesn't, so it's offered as a way to still get in touch.
+#[function_component(ErrorPage)]
+pub fn error_page() -> Html {
+    let messages = Locale::current().messages();
+
+    html! {
+        <main id={"main"} class={"error-page"}>
+            <h1>{messages.error_title}</h1>
+
+            <p>{messages.error_detail}</p>
+
+            if let Some(email) = contact_details().email() {
+                <p>
+                    {messages.error_contact}{" "}
+                    <a href={format!("mailto:{email}")}>{email}</a>
+                </p>
+            }
+        </main>
+    }
+}
@@ -0,0 +1,398 @@
+//! Export of the portfolio as a [JSON Resume](https://jsonresume.org/schema/).
+//!
+//! # Mapping
+//!
+//! | Content                 | JSON Resume                                        |
+//! | ----------------------- | -------------------------------------------------- |
+//! | [`ContactDetails`]      | `basics.name`, `basics.email`, `basics.url`        |
+//! | [`ContactLink`]         | `basics.email`, `basics.profiles[]`                |
+//! | [`Skill`]               | `skills[]`                                         |
+//! | [`Achievement`]         | `awards[]`                                         |
+//! | [`Creation`]            | `projects[]`                                       |
+//! | [`crate::Project`]      | `projects[]`, after the creations                  |
+//! | [`Article`]             | `publications[]`                                   |
+//! | [`Experience`] (work)   | `work[]`                                           |
+//! | [`Experience`] (other)  | `volunteer[]`                                      |
+//! | [`Education`]           | `education[]`                                      |
+//!
+//! Fields without a direct equivalent:
+//!
+//! - [`Competency`](crate::Competency) becomes the free-text `skills[].level`,
+//!   using its display name (e.g. `"Intermediate"`).
+//! - [`Area`](crate::Area)s, a skill's category, and the [`Tool`](crate::Tool)s
+//!   and skills used by achievements and creations all become `keywords`.
+//! - [`KiraDate`](crate::KiraDate)s become ISO 8601 dates. A creation's
+//!   `completed` date is its `endDate`, and a project's `started` date its
+//!   `startDate`; a skill's `started` date is not exported, as skills have no
+//!   dates in the schema.
+//! - A project's `url` is its live demo, or its repository if it has no demo.
+//!   Its status, licence, languages, releases and media have no
+//!   equivalent and are not exported.
+//! - Achievements have no awarder, so `awards[].awarder` is left out.
+//! - An education's qualification is its `studyType`; its areas and skills,
+//!   and those of experience, have no equivalent and are not exported.
+//! - Article topics, article content and skill history have no equivalent and
+//!   are not exported.
+//! - Content `id`s are internal and are not exported.
+//! - Translations are not exported; the resume is in the default
+//!   [`Locale`](crate::Locale).
+
+use crate::{
+    data::Portfolio, Achievement, Article, ContactDetails, ContactLink, Creation, Education,
+    Experience, ExperienceKind, KiraDate, Locale, Skill,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EducationList, ExperienceList, Localised};
+    use rstest::rstest;
+
+    fn portfolio() -> Portfolio {
+        let mut portfolio = Portfolio::load().expect("Failed to load portfolio");
+        portfolio.education = EducationList::new(vec![Education {
+            qualification: "BSc Computer Science".to_string(),
+            institution: "Example University".to_string(),
+            started: KiraDate::new(2021, 9, 1),
+            finished: None,
+            areas: vec![],
+            skills: vec![],
+            description: Localised::from("A degree."),
+        }]);
+        portfolio.experience = ExperienceList::new(vec![Experience {
+            role: "Developer".to_string(),
+            organisation: "Example Ltd".to_string(),
+            kind: ExperienceKind::Work,
+            started: KiraDate::new(2023, 6, 1),
+            ended: None,
+            areas: vec![],
+            skills: vec![],
+            description: Localised::from("A role."),
+        }]);
+        portfolio
+    }
+
+    #[rstest]
+    fn resume_serde_round_trip_works_as_expected() {
+        let resume = Resume::from(&portfolio());
+
+        let json = resume.to_json().expect("Failed to serialise resume");
+        let parsed: Resume = serde_json::from_str(&json).expect("Failed to deserialise resume");
+
+        assert_eq!(parsed, resume);
+    }
+
+    #[rstest]
+    fn resume_uses_json_resume_field_names() {
+        let json =
+            serde_json::to_value(Resume::from(&portfolio())).expect("Failed to serialise resume");
+
+        assert_eq!(json["basics"]["name"], "Kira Hudson");
+        assert!(json["basics"]["profiles"].is_array());
+        assert!(json["projects"][0]["endDate"].is_string());
+        assert!(json["projects"][1]["url"].is_string());
+        assert!(json["skills"][0]["level"].is_string());
+        assert!(json["work"][0]["startDate"].is_string());
+        assert!(json["education"][0]["studyType"].is_string());
+    }
+}
+
+/// The root of a JSON Resume document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Resume {
+    pub basics: Basics,
+    #[serde(default)]
+    pub skills: Vec<ResumeSkill>,
+    #[serde(default)]
+    pub awards: Vec<Award>,
+    #[serde(default)]
+    pub projects: Vec<Project>,
+    #[serde(default)]
+    pub publications: Vec<Publication>,
+    #[serde(default)]
+    pub work: Vec<Work>,
+    #[serde(default)]
+    pub volunteer: Vec<Volunteer>,
+    #[serde(default)]
+    pub education: Vec<ResumeEducation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Basics {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub profiles: Vec<Profile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    pub network: String,
+    pub username: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeSkill {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Award {
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Project {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Publication {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Work {
+    pub name: String,
+    pub position: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Volunteer {
+    pub organization: String,
+    pub position: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeEducation {
+    pub institution: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub study_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+}
+
+impl Resume {
+    /// Returns the resume as pretty-printed JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialisation fails.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+impl From<&Portfolio> for Resume {
+    fn from(portfolio: &Portfolio) -> Self {
+        Self {
+            basics: Basics::from(&portfolio.contact),
+            skills: portfolio.skills.iter().map(ResumeSkill::from).collect(),
+            awards: portfolio.achievements.iter().map(Award::from).collect(),
+            projects: portfolio
+                .creations
+                .iter()
+                .map(Project::from)
+                .chain(portfolio.projects.iter().map(Project::from))
+                .collect(),
+            publications: portfolio.articles.iter().map(Publication::from).collect(),
+            work: portfolio
+                .experience
+                .iter()
+                .filter(|experience| experience.kind == ExperienceKind::Work)
+                .map(Work::from)
+                .collect(),
+            volunteer: portfolio
+                .experience
+                .iter()
+                .filter(|experience| experience.kind != ExperienceKind::Work)
+                .map(Volunteer::from)
+                .collect(),
+            education: portfolio
+                .education
+                .iter()
+                .map(ResumeEducation::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<&ContactDetails> for Basics {
+    fn from(contact: &ContactDetails) -> Self {
+        Self {
+            name: contact.full_name(),
+            email: contact.email().map(ToString::to_string),
+            url: Some(contact.url.to_string()),
+            profiles: contact.social_links().map(Profile::from).collect(),
+        }
+    }
+}
+
+impl From<&ContactLink> for Profile {
+    fn from(link: &ContactLink) -> Self {
+        Self {
+            network: link.platform.name.to_string(),
+            username: link.handle.to_string(),
+            url: link.url(),
+        }
+    }
+}
+
+impl From<&Skill> for ResumeSkill {
+    fn from(skill: &Skill) -> Self {
+        Self {
+            name: skill.name.clone(),
+            level: Some(skill.competency.to_string()),
+            keywords: skill
+                .category
+                .iter()
+                .cloned()
+                .chain(skill.areas.iter().map(ToString::to_string))
+                .collect(),
+        }
+    }
+}
+
+impl From<&Achievement> for Award {
+    fn from(achievement: &Achievement) -> Self {
+        Self {
+            title: achievement.name.clone(),
+            date: Some(achievement.completed.to_iso_string()),
+            summary: Some(achievement.description.get(Locale::default()).to_string()),
+        }
+    }
+}
+
+impl From<&Creation> for Project {
+    fn from(creation: &Creation) -> Self {
+        Self {
+            name: creation.name.clone(),
+            description: Some(creation.description.get(Locale::default()).to_string()),
+            url: None,
+            start_date: None,
+            end_date: Some(creation.completed.to_iso_string()),
+            keywords: creation
+                .areas
+                .iter()
+                .map(ToString::to_string)
+                .chain(creation.tools.iter().map(ToString::to_string))
+                .chain(creation.skills.iter().map(|skill| skill.name.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl From<&crate::Project> for Project {
+    fn from(project: &crate::Project) -> Self {
+        Self {
+            name: project.name.clone(),
+            description: Some(project.description.get(Locale::default()).to_string()),
+            url: project.demo.clone().or_else(|| project.repository.clone()),
+            start_date: Some(project.started.to_iso_string()),
+            end_date: None,
+            keywords: project
+                .areas
+                .iter()
+                .map(ToString::to_string)
+                .chain(project.tools.iter().map(ToString::to_string))
+                .chain(project.skills.iter().map(|skill| skill.name.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl From<&Article> for Publication {
+    fn from(article: &Article) -> Self {
+        Self {
+            name: article.title.clone(),
+            release_date: Some(article.published.to_iso_string()),
+            summary: Some(article.summary.get(Locale::default()).to_string()),
+        }
+    }
+}
+
+impl From<&Experience> for Work {
+    fn from(experience: &Experience) -> Self {
+        Self {
+            name: experience.organisation.clone(),
+            position: experience.role.clone(),
+            start_date: Some(experience.started.to_iso_string()),
+            end_date: experience.ended.map(KiraDate::to_iso_string),
+            summary: Some(experience.description.get(Locale::default()).to_string()),
+        }
+    }
+}
+
+impl From<&Experience> for Volunteer {
+    fn from(experience: &Experience) -> Self {
+        Self {
+            organization: experience.organisation.clone(),
+            position: experience.role.clone(),
+            start_date: Some(experience.started.to_iso_string()),
+            end_date: experience.ended.map(KiraDate::to_iso_string),
+            summary: Some(experience.description.get(Locale::default()).to_string()),
+        }
+    }
+}
+
+impl From<&Education> for ResumeEducation {
+    fn from(education: &Education) -> Self {
+        Self {
+            institution: education.institution.clone(),
+            study_type: Some(education.qualification.clone()),
+            start_date: Some(education.started.to_iso_string()),
+            end_date: education.finished.map(KiraDate::to_iso_string),
+        }
+    }
+}
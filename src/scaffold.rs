@@ -0,0 +1,601 @@
+//! Generation of new content entries, for `kira-content new`.
+//!
+//! Content is written in Rust in [`Portfolio::load_unchecked`], so a new entry is
+//! generated as a struct literal and inserted at the end of its list in
+//! `src/data.rs`. Text the generator can't know, like a description, is left
+//! as a placeholder for `kira-content check` to flag until it's written.
+
+use crate::{data::Portfolio, slug, Get, Indexed, KiraDate};
+use std::fmt::Display;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn draft(kind: ContentKind, name: &str) -> Result<Draft, ScaffoldError> {
+        Draft::new(
+            kind,
+            name,
+            KiraDate::new(2024, 3, 9),
+            vec!["development/web".to_string()],
+            vec![],
+            vec![],
+            &Portfolio::load().expect("Failed to load portfolio"),
+        )
+    }
+
+    #[rstest]
+    fn draft_rejects_taken_names_and_unknown_references() {
+        assert_eq!(
+            draft(ContentKind::Creation, "web profile").err(),
+            Some(ScaffoldError::NameTaken("web-profile".to_string()))
+        );
+        assert_eq!(
+            Draft::new(
+                ContentKind::Creation,
+                "New",
+                KiraDate::new(2024, 3, 9),
+                vec![],
+                vec!["Notepad".to_string()],
+                vec![],
+                &Portfolio::load().expect("Failed to load portfolio"),
+            )
+            .err(),
+            Some(ScaffoldError::UnknownTool("Notepad".to_string()))
+        );
+        assert_eq!(
+            Draft::new(
+                ContentKind::Skill,
+                "New",
+                KiraDate::new(2024, 3, 9),
+                vec![],
+                vec!["Blender".to_string()],
+                vec![],
+                &Portfolio::load().expect("Failed to load portfolio"),
+            )
+            .err(),
+            Some(ScaffoldError::Unsupported(ContentKind::Skill, "tools"))
+        );
+        assert_eq!(
+            Draft::new(
+                ContentKind::Experience,
+                "New",
+                KiraDate::new(2024, 3, 9),
+                vec![],
+                vec!["Blender".to_string()],
+                vec![],
+                &Portfolio::load().expect("Failed to load portfolio"),
+            )
+            .err(),
+            Some(ScaffoldError::Unsupported(ContentKind::Experience, "tools"))
+        );
+    }
+
+    #[rstest]
+    #[case(ContentKind::Skill)]
+    #[case(ContentKind::Achievement)]
+    #[case(ContentKind::Creation)]
+    #[case(ContentKind::Project)]
+    #[case(ContentKind::Article)]
+    #[case(ContentKind::Education)]
+    #[case(ContentKind::Experience)]
+    fn insert_adds_the_entry_to_the_end_of_its_list(#[case] kind: ContentKind) {
+        let source = include_str!("data.rs");
+        let draft = draft(kind, "Brand New").expect("Failed to build draft");
+
+        let inserted = insert(source, &draft).expect("Failed to find the list");
+        let anchor = format!("{}::new(vec![", kind.list_type());
+        let open = inserted.find(&anchor).expect("List disappeared") + anchor.len() - 1;
+        let close = matching_bracket(&inserted, open).expect("List left unclosed");
+        let entry = inserted.find("\"Brand New\"").expect("Entry missing");
+
+        assert!(open < entry && entry < close);
+        assert!(source.ends_with(&inserted[close..]));
+    }
+
+    #[rstest]
+    #[case("[1, [2, 3], (4)]")]
+    #[case("[\"]\", \"\\\"]\"]")]
+    #[case("[r#\"]\"#, br\"]\"]")]
+    #[case("[']', '\\'', '\\u{5d}', 'é']")]
+    #[case("[&'a str, 'b']")]
+    #[case("[// ]\n]")]
+    #[case("[/* ] /* ] */ ] */]")]
+    fn matching_bracket_skips_literals_and_comments(#[case] source: &str) {
+        assert_eq!(matching_bracket(source, 0), Some(source.len() - 1));
+    }
+
+    #[rstest]
+    fn insert_returns_none_without_the_list() {
+        let draft = draft(ContentKind::Article, "Brand New").expect("Failed to build draft");
+
+        assert_eq!(insert("fn main() {}", &draft), None);
+    }
+}
+
+/// The kinds of content that can be scaffolded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentKind {
+    Skill,
+    Achievement,
+    Creation,
+    Project,
+    Article,
+    Education,
+    Experience,
+}
+
+impl ContentKind {
+    /// Every kind, for listing in usage messages.
+    pub const ALL: [Self; 7] = [
+        Self::Skill,
+        Self::Achievement,
+        Self::Creation,
+        Self::Project,
+        Self::Article,
+        Self::Education,
+        Self::Experience,
+    ];
+
+    /// Returns the kind with the given command-line name, like `"skill"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.to_string().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the name of the kind's Rust type, like `"Skill"`.
+    pub const fn type_name(self) -> &'static str {
+        match self {
+            Self::Skill => "Skill",
+            Self::Achievement => "Achievement",
+            Self::Creation => "Creation",
+            Self::Project => "Project",
+            Self::Article => "Article",
+            Self::Education => "Education",
+            Self::Experience => "Experience",
+        }
+    }
+
+    /// Returns the name of the kind's list, like `"skills"`.
+    pub const fn list_name(self) -> &'static str {
+        match self {
+            Self::Skill => "skills",
+            Self::Achievement => "achievements",
+            Self::Creation => "creations",
+            Self::Project => "projects",
+            Self::Article => "articles",
+            Self::Education => "education",
+            Self::Experience => "experience",
+        }
+    }
+
+    /// Returns the name of the kind's list type, like `"SkillList"`.
+    pub const fn list_type(self) -> &'static str {
+        match self {
+            Self::Skill => "SkillList",
+            Self::Achievement => "AchievementList",
+            Self::Creation => "CreationList",
+            Self::Project => "ProjectList",
+            Self::Article => "ArticleList",
+            Self::Education => "EducationList",
+            Self::Experience => "ExperienceList",
+        }
+    }
+
+    /// Returns `true` if an item of this kind in the portfolio has the given
+    /// slug.
+    fn has_slug(self, portfolio: &Portfolio, slug: &str) -> bool {
+        match self {
+            Self::Skill => portfolio.skills.get_by_slug(slug).is_some(),
+            Self::Achievement => portfolio.achievements.get_by_slug(slug).is_some(),
+            Self::Creation => portfolio.creations.get_by_slug(slug).is_some(),
+            Self::Project => portfolio.projects.get_by_slug(slug).is_some(),
+            Self::Article => portfolio.articles.get_by_slug(slug).is_some(),
+            Self::Education => portfolio.education.get_by_slug(slug).is_some(),
+            Self::Experience => portfolio.experience.get_by_slug(slug).is_some(),
+        }
+    }
+
+    /// Returns whether the kind can reference the given field's items, one of
+    /// `"areas"`, `"tools"` or `"skills"`.
+    pub fn supports(self, field: &str) -> bool {
+        !matches!(
+            (self, field),
+            (Self::Skill, "tools" | "skills")
+                | (Self::Article, "skills")
+                | (Self::Education | Self::Experience, "tools")
+        )
+    }
+}
+
+impl Display for ContentKind {
+    /// Writes the kind's command-line name, like `skill`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.type_name().to_lowercase())
+    }
+}
+
+/// The reasons a [`Draft`] can't be made or inserted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScaffoldError {
+    /// The name has no letters or digits to make a slug from.
+    EmptyName,
+    /// Another item of the same kind has the same slug.
+    NameTaken(String),
+    UnknownArea(String),
+    UnknownTool(String),
+    UnknownSkill(String),
+    /// The kind has no field for the given references, like a skill's tools.
+    Unsupported(ContentKind, &'static str),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EmptyName => write!(f, "The name needs at least one letter or digit"),
+            Self::NameTaken(slug) => write!(f, "The slug `{slug}` is already taken"),
+            Self::UnknownArea(area) => write!(f, "There is no area `{area}`"),
+            Self::UnknownTool(tool) => write!(f, "There is no tool `{tool}`"),
+            Self::UnknownSkill(skill) => write!(f, "There is no skill `{skill}`"),
+            Self::Unsupported(kind, field) => write!(f, "A {kind} can't have {field}"),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// A new content item, checked against the portfolio it's going into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Draft {
+    pub kind: ContentKind,
+    pub name: String,
+    /// When the skill, project, education or experience was started, the
+    /// achievement or creation completed, or the article published.
+    pub date: KiraDate,
+    pub areas: Vec<String>,
+    pub tools: Vec<String>,
+    pub skills: Vec<String>,
+}
+
+impl Draft {
+    /// Returns a draft of an item of the given kind.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the name's slug is empty or already taken by an item of the
+    /// same kind, or if a reference doesn't exist or doesn't apply to the
+    /// kind.
+    pub fn new(
+        kind: ContentKind,
+        name: &str,
+        date: KiraDate,
+        areas: Vec<String>,
+        tools: Vec<String>,
+        skills: Vec<String>,
+        portfolio: &Portfolio,
+    ) -> Result<Self, ScaffoldError> {
+        let name = name.trim().to_string();
+        let name_slug = slug(&name);
+
+        if name_slug.is_empty() {
+            return Err(ScaffoldError::EmptyName);
+        }
+        if kind.has_slug(portfolio, &name_slug) {
+            return Err(ScaffoldError::NameTaken(name_slug));
+        }
+
+        for (field, references) in [("areas", &areas), ("tools", &tools), ("skills", &skills)] {
+            if !references.is_empty() && !kind.supports(field) {
+                return Err(ScaffoldError::Unsupported(kind, field));
+            }
+        }
+        if let Some(area) = areas
+            .iter()
+            .find(|area| portfolio.areas.get(area).is_none())
+        {
+            return Err(ScaffoldError::UnknownArea(area.clone()));
+        }
+        if let Some(tool) = tools
+            .iter()
+            .find(|tool| portfolio.tools.get(tool).is_none())
+        {
+            return Err(ScaffoldError::UnknownTool(tool.clone()));
+        }
+        if let Some(skill) = skills
+            .iter()
+            .find(|skill| portfolio.skills.get(skill).is_none())
+        {
+            return Err(ScaffoldError::UnknownSkill(skill.clone()));
+        }
+
+        Ok(Self {
+            kind,
+            name,
+            date,
+            areas,
+            tools,
+            skills,
+        })
+    }
+
+    /// Returns the draft's slug, which no other item of its kind may share.
+    pub fn slug(&self) -> String {
+        slug(&self.name)
+    }
+
+    /// Returns the draft as a Rust struct literal for `src/data.rs`.
+    ///
+    /// Types and macros are written with their full paths, since `src/data.rs`
+    /// only imports the ones its content already uses.
+    pub fn to_rust(&self) -> String {
+        let (list, name) = (self.kind.list_name(), &self.name);
+        let areas = self.areas.iter().map(|area| {
+            format!("Rc::new(areas.get_or_record({area:?}, {list:?}, {name:?}, &mut errors))")
+        });
+        let tools = self.tools.iter().map(|tool| {
+            format!("Rc::new(tools.get_or_record({tool:?}, {list:?}, {name:?}, &mut errors))")
+        });
+        let skills = self.skills.iter().map(|skill| {
+            format!("Rc::new(skills.get_or_record({skill:?}, {list:?}, {name:?}, &mut errors))")
+        });
+        let date = format!(
+            "KiraDate::new({}, {}, {})",
+            self.date.get_year(),
+            self.date.get_month(),
+            self.date.get_day()
+        );
+
+        let mut fields = Vec::new();
+        match self.kind {
+            ContentKind::Skill => {
+                fields.push(format!("name: {:?}.to_string()", self.name));
+                fields.push(vec_literal("areas", areas));
+                fields.push("competency: Competency::Novice".to_string());
+                fields.push("history: vec![]".to_string());
+                fields.push("category: None".to_string());
+                fields.push(format!("started: Some({date})"));
+                fields.push("last_used: None".to_string());
+                fields.push("description: Localised::from(\"<DESCRIPTION>\")".to_string());
+            }
+            ContentKind::Achievement | ContentKind::Creation => {
+                fields.push(format!("name: {:?}.to_string()", self.name));
+                fields.push(format!("completed: {date}"));
+                fields.push(vec_literal("areas", areas));
+                fields.push(vec_literal("tools", tools));
+                fields.push(vec_literal("skills", skills));
+                fields.push("description: Localised::from(\"<DESCRIPTION>\")".to_string());
+                fields.push("media: vec![]".to_string());
+            }
+            ContentKind::Project => {
+                fields.push(format!("name: {:?}.to_string()", self.name));
+                fields.push(format!("started: {date}"));
+                fields.push("status: crate::ProjectStatus::Active".to_string());
+                fields.push("repository: None".to_string());
+                fields.push("demo: None".to_string());
+                fields.push("licence: None".to_string());
+                fields.push("languages: vec![]".to_string());
+                fields.push("media: vec![]".to_string());
+                fields.push("releases: vec![]".to_string());
+                fields.push(vec_literal("areas", areas));
+                fields.push(vec_literal("tools", tools));
+                fields.push(vec_literal("skills", skills));
+                fields.push("description: Localised::from(\"<DESCRIPTION>\")".to_string());
+            }
+            ContentKind::Education => {
+                fields.push(format!("qualification: {:?}.to_string()", self.name));
+                fields.push("institution: \"<INSTITUTION>\".to_string()".to_string());
+                fields.push(format!("started: {date}"));
+                fields.push("finished: None".to_string());
+                fields.push(vec_literal("areas", areas));
+                fields.push(vec_literal("skills", skills));
+                fields.push("description: Localised::from(\"<DESCRIPTION>\")".to_string());
+            }
+            ContentKind::Experience => {
+                fields.push(format!("role: {:?}.to_string()", self.name));
+                fields.push("organisation: \"<ORGANISATION>\".to_string()".to_string());
+                fields.push("kind: crate::ExperienceKind::Work".to_string());
+                fields.push(format!("started: {date}"));
+                fields.push("ended: None".to_string());
+                fields.push(vec_literal("areas", areas));
+                fields.push(vec_literal("skills", skills));
+                fields.push("description: Localised::from(\"<DESCRIPTION>\")".to_string());
+            }
+            ContentKind::Article => {
+                let topics = areas
+                    .map(|area| format!("Rc::new(crate::Topic::Area({area}))"))
+                    .chain(tools.map(|tool| format!("Rc::new(crate::Topic::Tool({tool}))")));
+
+                fields.push(format!("title: {:?}.to_string()", self.name));
+                fields.push(format!("published: {date}"));
+                fields.push(vec_literal("topics", topics));
+                fields.push("summary: Localised::from(\"<SUMMARY>\")".to_string());
+                fields.push(
+                    "content: yew::html! {\n    <div>\n        <p>{\"<CONTENT>\"}</p>\n    </div>\n}"
+                        .to_string(),
+                );
+            }
+        }
+
+        let body = fields
+            .iter()
+            .map(|field| {
+                field
+                    .lines()
+                    .map(|line| format!("    {line}"))
+                    .collect::<Vec<String>>()
+                    .join("\n")
+                    + ",\n"
+            })
+            .collect::<String>();
+
+        format!("crate::{} {{\n{body}}}", self.kind.type_name())
+    }
+}
+
+/// Returns a field holding a `vec!` of the given items, one per line.
+fn vec_literal(field: &str, items: impl Iterator<Item = String>) -> String {
+    let items = items
+        .map(|item| format!("    {item},"))
+        .collect::<Vec<String>>();
+
+    if items.is_empty() {
+        format!("{field}: vec![]")
+    } else {
+        format!("{field}: vec![\n{}\n]", items.join("\n"))
+    }
+}
+
+/// Returns `source` with the draft added to the end of its list's `vec!`, or
+/// `None` if the list can't be found.
+///
+/// The entry is indented one level deeper than the line the list starts on.
+/// `rustfmt` leaves lists with long lines alone, so this is how it stays
+/// readable, but a list with one item on the same line as its brackets should
+/// still be run through `rustfmt` afterwards.
+pub fn insert(source: &str, draft: &Draft) -> Option<String> {
+    let anchor = format!("{}::new(vec![", draft.kind.list_type());
+    let start = source.find(&anchor)?;
+    let open = start + anchor.len() - 1;
+    let close = matching_bracket(source, open)?;
+
+    let line = &source[source[..start].rfind('\n').map_or(0, |index| index + 1)..start];
+    let indent = &line[..line.len() - line.trim_start().len()];
+    let entry = draft
+        .to_rust()
+        .lines()
+        .map(|line| format!("{indent}    {line}"))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let before = source[..close].trim_end();
+    let separator = if before.ends_with('[') || before.ends_with(',') {
+        ""
+    } else {
+        ","
+    };
+
+    Some(format!(
+        "{before}{separator}\n{entry},\n{indent}{}",
+        &source[close..]
+    ))
+}
+
+/// Returns the index of the bracket closing the one at `open`, skipping over
+/// the brackets in comments and in string, raw string and character literals.
+fn matching_bracket(source: &str, open: usize) -> Option<usize> {
+    let bytes = source.as_bytes();
+    let mut depth = 0_usize;
+    let mut index = open;
+
+    while index < bytes.len() {
+        let rest = &bytes[index..];
+
+        match rest[0] {
+            b'/' if rest.starts_with(b"//") => {
+                index = find_from(bytes, index, b"\n").unwrap_or(bytes.len());
+            }
+            b'/' if rest.starts_with(b"/*") => index = block_comment_end(bytes, index)?,
+            b'"' => index = string_end(bytes, index + 1)?,
+            b'r' => index = raw_string_end(bytes, index).unwrap_or(index),
+            b'\'' => index = char_end(source, index).unwrap_or(index),
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    None
+}
+
+/// Returns the index of the first `needle` at or after `from`.
+fn find_from(bytes: &[u8], from: usize, needle: &[u8]) -> Option<usize> {
+    bytes
+        .get(from..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|position| from + position)
+}
+
+/// Returns the index of the `/` ending the block comment starting at `start`,
+/// which may have others nested inside it.
+fn block_comment_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut depth = 0_usize;
+    let mut index = start;
+
+    while index + 1 < bytes.len() {
+        match &bytes[index..index + 2] {
+            b"/*" => {
+                depth += 1;
+                index += 2;
+            }
+            b"*/" => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index + 1);
+                }
+                index += 2;
+            }
+            _ => index += 1,
+        }
+    }
+
+    None
+}
+
+/// Returns the index of the `"` ending the string whose contents start at
+/// `start`.
+fn string_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut index = start;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b'"' => return Some(index),
+            _ => index += 1,
+        }
+    }
+
+    None
+}
+
+/// Returns the index of the last `#` or `"` ending the raw string starting
+/// at the `r` at `start`, like `r#"..."#`, or `None` if it isn't one.
+fn raw_string_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let hashes = bytes
+        .get(start + 1..)?
+        .iter()
+        .take_while(|byte| **byte == b'#')
+        .count();
+    if bytes.get(start + 1 + hashes) != Some(&b'"') {
+        return None;
+    }
+
+    let mut close = vec![b'"'];
+    close.resize(hashes + 1, b'#');
+
+    find_from(bytes, start + 2 + hashes, &close).map(|index| index + hashes)
+}
+
+/// Returns the index of the `'` ending the character literal starting at
+/// `start`, or `None` if it starts a lifetime instead, like `'a`.
+fn char_end(source: &str, start: usize) -> Option<usize> {
+    let bytes = source.as_bytes();
+
+    if bytes.get(start + 1) == Some(&b'\\') {
+        // Skip the escaped character, which may itself be a `'`.
+        return find_from(bytes, start + 3, b"'");
+    }
+
+    let length = source.get(start + 1..)?.chars().next()?.len_utf8();
+
+    (bytes.get(start + 1 + length) == Some(&b'\'')).then_some(start + 1 + length)
+}
@@ -0,0 +1,135 @@
+use crate::Locale;
+use std::fmt::Display;
+use yew::prelude::*;
+
+/// The `localStorage` key the visitor's chosen theme is saved under. Also read
+/// by the script in `index.html` that applies the theme before the app loads.
+pub const THEME_STORAGE_KEY: &str = "theme";
+
+/// A colour theme. The colours themselves are CSS custom properties in
+/// `style.css`, selected by the `data-theme` attribute of the root element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Theme {
+    /// Follow the visitor's `prefers-color-scheme` and `prefers-contrast`.
+    System,
+    Light,
+    Dark,
+    HighContrast,
+}
+
+impl Theme {
+    /// Every theme, in the order they are offered.
+    pub const ALL: [Self; 4] = [Self::System, Self::Light, Self::Dark, Self::HighContrast];
+
+    /// Returns the value of the root element's `data-theme` attribute, or
+    /// `None` if the attribute should be removed to follow the system.
+    pub const fn attribute(self) -> Option<&'static str> {
+        match self {
+            Self::System => None,
+            Self::Light => Some("light"),
+            Self::Dark => Some("dark"),
+            Self::HighContrast => Some("high-contrast"),
+        }
+    }
+
+    /// Returns the theme with the given `data-theme` attribute value, or
+    /// [`Theme::System`] if there is no such theme.
+    pub fn from_attribute(attribute: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|theme| theme.attribute() == Some(attribute))
+            .unwrap_or(Self::System)
+    }
+
+    /// Returns the Font Awesome classes of the theme's icon.
+    pub const fn icon(self) -> &'static str {
+        match self {
+            Self::System => "fa-solid fa-circle-half-stroke",
+            Self::Light => "fa-solid fa-sun",
+            Self::Dark => "fa-solid fa-moon",
+            Self::HighContrast => "fa-solid fa-eye",
+        }
+    }
+
+    /// Returns the theme saved in `localStorage`, or [`Theme::System`] if none
+    /// is saved or storage is unavailable.
+    pub fn stored() -> Self {
+        // There is no browser to ask when rendering natively, as in tests.
+        if cfg!(not(target_arch = "wasm32")) {
+            return Self::System;
+        }
+
+        web_sys::window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .and_then(|storage| storage.get_item(THEME_STORAGE_KEY).ok().flatten())
+            .map_or(Self::System, |theme| Self::from_attribute(&theme))
+    }
+
+    /// Applies the theme to the page and saves it in `localStorage`.
+    ///
+    /// Failures are ignored, as the page is still usable in the system theme.
+    pub fn apply(self) {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+
+        if let Some(root) = window
+            .document()
+            .and_then(|document| document.document_element())
+        {
+            let _ = self.attribute().map_or_else(
+                || root.remove_attribute("data-theme"),
+                |attribute| root.set_attribute("data-theme", attribute),
+            );
+        }
+
+        if let Ok(Some(storage)) = window.local_storage() {
+            let _ = self.attribute().map_or_else(
+                || storage.remove_item(THEME_STORAGE_KEY),
+                |attribute| storage.set_item(THEME_STORAGE_KEY, attribute),
+            );
+        }
+    }
+}
+
+impl Display for Theme {
+    /// Writes the theme's name in the current locale.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Locale::current().messages().theme(*self))
+    }
+}
+
+/// Buttons for choosing a [`Theme`], remembered between visits.
+#[function_component(ThemeToggle)]
+pub fn theme_toggle() -> Html {
+    let theme = use_state(Theme::stored);
+
+    html! {
+        <div
+            class={"theme-toggle"}
+            role={"group"}
+            aria-label={Locale::current().messages().colour_theme}
+        >
+            {Theme::ALL.into_iter().map(|option| {
+                let theme = theme.clone();
+                let pressed = *theme == option;
+                let onclick = Callback::from(move |_| {
+                    option.apply();
+                    theme.set(option);
+                });
+
+                html! {
+                    <button
+                        class={classes!("theme-option", pressed.then_some("active"))}
+                        title={option.to_string()}
+                        aria-label={option.to_string()}
+                        aria-pressed={pressed.to_string()}
+                        {onclick}
+                    >
+                        <i class={option.icon()} aria-hidden={"true"}></i>
+                    </button>
+                }
+            }).collect::<Html>()}
+        </div>
+    }
+}
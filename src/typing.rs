@@ -1,53 +1,54 @@
+use crate::{DateFormat, DateOrder, DateStyle};
 use chrono::{prelude::*, LocalResult};
 use std::fmt::Display;
 
-////#[cfg(test)]
-////mod tests {
-////    use super::*;
-////    use rstest::rstest;
-////
-////    #[rstest]
-////    fn kira_date_to_string_fmtd_works_as_expected() {
-////        let date = KiraDate::new(2024, 2, 1);
-////
-////        assert_eq!(
-////            date.to_string_fmtd(Some("Y-M-D")),
-////            format!(
-////                "{}-{}-{}",
-////                date.get_year(),
-////                date.get_month(),
-////                date.get_day()
-////            )
-////        );
-////        assert_eq!(
-////            date.to_string_fmtd(Some("d/m/y")),
-////            format!(
-////                "{}/{}/{}",
-////                date.get_day(),
-////                date.get_month(),
-////                date.get_year()
-////            )
-////        );
-////        assert_eq!(
-////            date.to_string_fmtd(Some("Y.M.D")),
-////            format!(
-////                "{}.{}.{}",
-////                date.get_day(),
-////                date.get_month(),
-////                date.get_year()
-////            )
-////        );
-////        assert_eq!(
-////            date.to_string_fmtd(None),
-////            format!(
-////                "{}/{}/{}",
-////                date.get_day(),
-////                date.get_month(),
-////                date.get_year()
-////            )
-////        );
-////    }
-////}
+//#[cfg(test)]
+//mod tests {
+//    use super::*;
+//    use rstest::rstest;
+//
+//    #[rstest]
+//    fn kira_date_to_string_fmtd_works_as_expected() {
+//        let date = KiraDate::new(2024, 2, 1);
+//
+//        assert_eq!(
+//            date.to_string_fmtd(Some("Y-M-D")),
+//            format!(
+//                "{}-{}-{}",
+//                date.get_year(),
+//                date.get_month(),
+//                date.get_day()
+//            )
+//        );
+//        assert_eq!(
+//            date.to_string_fmtd(Some("d/m/y")),
+//            format!(
+//                "{}/{}/{}",
+//                date.get_day(),
+//                date.get_month(),
+//                date.get_year()
+//            )
+//        );
+//        assert_eq!(
+//            date.to_string_fmtd(Some("Y.M.D")),
+//            format!(
+//                "{}.{}.{}",
+//                date.get_day(),
+//                date.get_month(),
+//                date.get_year()
+//            )
+//        );
+//        assert_eq!(
+//            date.to_string_fmtd(None),
+//            format!(
+//                "{}/{}/{}",
+//                date.get_day(),
+//                date.get_month(),
+//                date.get_year()
+//            )
+//        );
+//    }
+//}
 
 pub type Year = i32;
 pub type Month = u8;
@@ -66,6 +67,20 @@ impl KiraDate {
         Self { year, month, day }
     }
 
+    /// Returns today's date, in UTC.
+    pub fn today() -> Self {
+        LocalResult::Single(Utc::now()).into()
+    }
+
+    /// Returns the number of whole years from the calling `KiraDate` until
+    /// `other`, or `0` if `other` is not after the calling date.
+    pub fn years_until(self, other: Self) -> Year {
+        let years =
+            other.year - self.year - Year::from((other.month, other.day) < (self.month, self.day));
+
+        years.max(0)
+    }
+
     /// Return a string formatted in either day-month-year or year-month-day
     /// arrangement, and with any of the three separators `/`, `.` or `-`.
     ///
@@ -83,11 +98,12 @@ impl KiraDate {
     /// # Examples
     ///
     /// ```
+    /// # use kira_me_website::KiraDate;
     /// let date = KiraDate::new(2024, 2, 1);
     ///
     /// assert_eq!(date.to_string_fmtd(Some("Y-M-D")), format!("{}-{}-{}", date.get_year(), date.get_month(), date.get_day()));
     /// assert_eq!(date.to_string_fmtd(Some("d/m/y")), format!("{}/{}/{}", date.get_day(), date.get_month(), date.get_year()));
-    /// assert_eq!(date.to_string_fmtd(Some("Y.M.D")), format!("{}.{}.{}", date.get_day(), date.get_month(), date.get_year()));
+    /// assert_eq!(date.to_string_fmtd(Some("Y.M.D")), format!("{}.{}.{}", date.get_year(), date.get_month(), date.get_day()));
     /// assert_eq!(date.to_string_fmtd(None), format!("{}/{}/{}", date.get_day(), date.get_month(), date.get_year()));
     /// ```
     pub fn to_string_fmtd(self, format: Option<&str>) -> String {
@@ -96,7 +112,7 @@ impl KiraDate {
             |fstr| match fstr.to_lowercase().as_str() {
                 "y-m-d" => format!("{}-{}-{}", self.year, self.month, self.day),
                 "d-m-y" => format!("{}-{}-{}", self.day, self.month, self.year),
-                "y.m.d" => format!("{}.{}.{}", self.year, self.month, self.year),
+                "y.m.d" => format!("{}.{}.{}", self.year, self.month, self.day),
                 "d.m.y" => format!("{}.{}.{}", self.day, self.month, self.year),
                 "y/m/d" => format!("{}/{}/{}", self.year, self.month, self.day),
                 _ => format!("{}/{}/{}", self.day, self.month, self.year),
@@ -104,6 +120,69 @@ impl KiraDate {
         )
     }
 
+    /// Returns the date as an ISO 8601 string (`YYYY-MM-DD`), for feeds and
+    /// other machine-readable outputs.
+    pub fn to_iso_string(self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+
+    /// Parses an ISO 8601 date string (`YYYY-MM-DD`), or returns `None` if it
+    /// isn't one or the date is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use kira_me_website::KiraDate;
+    /// assert_eq!(KiraDate::from_iso_string("2023-08-17"), Some(KiraDate::new(2023, 8, 17)));
+    /// assert_eq!(KiraDate::from_iso_string("2023-02-31"), None);
+    /// assert_eq!(KiraDate::from_iso_string("17/08/2023"), None);
+    /// ```
+    pub fn from_iso_string(string: &str) -> Option<Self> {
+        let mut parts = string.trim().splitn(3, '-');
+        let date = Self::new(
+            parts.next()?.parse().ok()?,
+            parts.next()?.parse().ok()?,
+            parts.next()?.parse().ok()?,
+        );
+
+        date.is_valid().then_some(date)
+    }
+
+    /// Returns the date written out in the given style and format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use kira_me_website::{DateFormat, DateStyle, KiraDate, Locale};
+    /// let date = KiraDate::new(2023, 8, 17);
+    /// let british = DateFormat::for_tag(Locale::English, "en-GB");
+    /// let american = DateFormat::for_tag(Locale::English, "en-US");
+    ///
+    /// assert_eq!(date.format(DateStyle::Long, british), "17 August 2023");
+    /// assert_eq!(date.format(DateStyle::Long, american), "August 17, 2023");
+    /// assert_eq!(date.format(DateStyle::Short, american), "08/17/2023");
+    /// ```
+    pub fn format(self, style: DateStyle, date_format: DateFormat) -> String {
+        let messages = date_format.locale.messages();
+        let (year, month, day) = (self.year, self.month, self.day);
+        let names = match style {
+            DateStyle::Long => &messages.months,
+            DateStyle::Medium | DateStyle::Short => &messages.short_months,
+        };
+        let name = names
+            .get(usize::from(month).wrapping_sub(1))
+            .map_or_else(|| month.to_string(), ToString::to_string);
+
+        match (style, date_format.order) {
+            (DateStyle::Short, DateOrder::DayMonthYear) => format!("{day:02}/{month:02}/{year}"),
+            (DateStyle::Short, DateOrder::MonthDayYear) => format!("{month:02}/{day:02}/{year}"),
+            (DateStyle::Short, DateOrder::YearMonthDay) => self.to_iso_string(),
+            (_, DateOrder::DayMonthYear) => format!("{day} {name} {year}"),
+            (_, DateOrder::MonthDayYear) => format!("{name} {day}, {year}"),
+            (_, DateOrder::YearMonthDay) => format!("{year} {name} {day}"),
+        }
+    }
+
     /// Returns the day of the month of the date.
     pub const fn get_day(self) -> Day {
         self.day
@@ -158,6 +237,7 @@ impl KiraDate {
 
     /// Returns a new `KiraDate` with the year, month and day clamped to valid
     /// values.
+    #[must_use]
     pub fn make_valid(mut self) -> Self {
         self.month = self.month.clamp(1, 12);
         self.day = self.day.clamp(1, self.get_days_in_month());
@@ -209,7 +289,13 @@ impl From<KiraDate> for LocalResult<DateTime<Utc>> {
 }
 
 impl Display for KiraDate {
+    /// Writes the date in the short style of the
+    /// [current](DateFormat::current) format, like `17/08/2023`.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.to_string_fmtd(None))
+        write!(
+            f,
+            "{}",
+            self.format(DateStyle::Short, DateFormat::current())
+        )
     }
 }
@@ -0,0 +1,360 @@
+//! Renders every route of the site and checks the markup against a set of
+//! accessibility rules.
+//!
+//! The rules are the subset of WCAG that can be checked from the markup alone:
+//!
+//! - the first link skips to the main content, which exists and is focusable;
+//! - each page has exactly one `<h1>`, which comes first, and heading levels
+//!   never skip (e.g. from `<h2>` to `<h4>`);
+//! - links and buttons have an accessible name, from their text, an image's
+//!   `alt` text or `aria-label`;
+//! - images have `alt` text, which may be empty for decorative images;
+//! - form fields have a label;
+//! - icon fonts are hidden from assistive technology;
+//! - `id`s are unique.
+//!
+//! Contrast, focus order and motion depend on the stylesheet and browser, and
+//! are checked by hand.
+
+use kira_me_website::{
+    data::Portfolio, Education, EducationList, Experience, ExperienceKind, ExperienceList,
+    KiraDate, Localised, Route, Site,
+};
+use rstest::rstest;
+use scraper::{ElementRef, Node, Selector};
+use std::{collections::HashMap, rc::Rc};
+use yew::{prelude::*, ServerRenderer};
+use yew_router::{
+    history::{AnyHistory, History, MemoryHistory},
+    prelude::*,
+};
+
+// ******************************** Rendering ******************************* //
+// ************************************************************************** //
+
+/// The site's content, with an education and an experience entry so that
+/// their pages are rendered too.
+fn portfolio() -> Portfolio {
+    let mut portfolio = Portfolio::load().expect("Failed to load portfolio");
+    portfolio.education = EducationList::new(vec![Education {
+        qualification: "BSc Computer Science".to_string(),
+        institution: "Example University".to_string(),
+        started: KiraDate::new(2021, 9, 1),
+        finished: None,
+        areas: vec![],
+        skills: vec![],
+        description: Localised::from("A degree."),
+    }]);
+    portfolio.experience = ExperienceList::new(vec![Experience {
+        role: "Developer".to_string(),
+        organisation: "Example Ltd".to_string(),
+        kind: ExperienceKind::Work,
+        started: KiraDate::new(2023, 6, 1),
+        ended: None,
+        areas: vec![],
+        skills: vec![],
+        description: Localised::from("A role."),
+    }]);
+    portfolio
+}
+
+#[derive(Properties, PartialEq, Eq)]
+struct PageProperties {
+    path: String,
+}
+
+/// The site as it would be rendered in the browser at `path`.
+#[function_component(Page)]
+fn page(PageProperties { path }: &PageProperties) -> Html {
+    let history = AnyHistory::from(MemoryHistory::new());
+    history.push(path);
+
+    html! {
+        <Router {history}>
+            <Site portfolio={Rc::new(portfolio())} />
+        </Router>
+    }
+}
+
+/// Returns the markup of the page for `route`.
+async fn render(route: &Route) -> String {
+    let path = route.to_path();
+
+    ServerRenderer::<Page>::with_props(move || PageProperties { path })
+        .hydratable(false)
+        .render()
+        .await
+}
+
+/// Returns every route to a content item in the portfolio.
+fn content_routes() -> Vec<Route> {
+    let portfolio = portfolio();
+
+    portfolio
+        .skills
+        .iter()
+        .map(|skill| Route::Skill {
+            skill: skill.name.clone(),
+        })
+        .chain(
+            portfolio
+                .achievements
+                .iter()
+                .map(|achievement| Route::Achievement {
+                    achievement: achievement.name.clone(),
+                }),
+        )
+        .chain(portfolio.creations.iter().map(|creation| Route::Creation {
+            creation: creation.name.clone(),
+        }))
+        .chain(portfolio.projects.iter().map(|project| Route::Project {
+            project: project.name.clone(),
+        }))
+        .chain(portfolio.articles.iter().map(|article| Route::Article {
+            article: article.title.clone(),
+        }))
+        .chain(
+            portfolio
+                .education
+                .iter()
+                .map(|education| Route::Qualification {
+                    qualification: education.qualification.clone(),
+                }),
+        )
+        .chain(portfolio.experience.iter().map(|experience| Route::Role {
+            role: experience.role.clone(),
+        }))
+        .chain(portfolio.areas.iter().map(|area| Route::Area {
+            area: area.0.to_string(),
+        }))
+        .collect()
+}
+
+// ********************************* Parsing ******************************** //
+// ************************************************************************** //
+
+/// An element of the rendered page, with what the rules need to know about
+/// it.
+#[derive(Debug)]
+struct Element {
+    name: String,
+    attributes: HashMap<String, String>,
+    /// The text of the element and its descendants, including the `alt` text
+    /// of descendant images.
+    text: String,
+    /// Whether the element is inside a `<label>`.
+    in_label: bool,
+}
+
+impl Element {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.get(name).map(String::as_str)
+    }
+
+    /// Returns `true` if the element has a non-empty accessible name.
+    fn has_name(&self) -> bool {
+        !self.text.trim().is_empty()
+            || self
+                .attribute("aria-label")
+                .is_some_and(|label| !label.trim().is_empty())
+    }
+
+    /// Returns the level of a heading element, or `None` for other elements.
+    fn heading_level(&self) -> Option<u8> {
+        match self.name.as_bytes() {
+            [b'h', level @ b'1'..=b'6'] => Some(level - b'0'),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the text of an element and its descendants, including the `alt`
+/// text of descendant images.
+fn accessible_text(element: ElementRef) -> String {
+    element
+        .descendants()
+        .filter_map(|node| match node.value() {
+            Node::Text(text) => Some(&**text),
+            Node::Element(child) if child.name() == "img" => child.attr("alt"),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Parses rendered markup into its elements, in document order.
+fn parse(html: &str) -> Vec<Element> {
+    let document = scraper::Html::parse_document(html);
+    let every = Selector::parse("*").expect("Invalid selector");
+
+    document
+        .select(&every)
+        .map(|element| Element {
+            name: element.value().name().to_string(),
+            attributes: element
+                .value()
+                .attrs()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            text: accessible_text(element),
+            in_label: element
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .any(|ancestor| ancestor.value().name() == "label"),
+        })
+        .collect()
+}
+
+// ********************************** Rules ********************************* //
+// ************************************************************************** //
+
+/// Returns a description of every rule the page breaks.
+fn violations(elements: &[Element]) -> Vec<String> {
+    let mut violations = Vec::new();
+
+    // Skip link.
+    let first_link = elements.iter().find(|element| element.name == "a");
+    if first_link.and_then(|link| link.attribute("href")) != Some("#main") {
+        violations.push(String::from("the first link does not skip to #main"));
+    }
+    let main = elements
+        .iter()
+        .find(|element| element.attribute("id") == Some("main"));
+    if main.and_then(|main| main.attribute("tabindex")) != Some("-1") {
+        violations.push(String::from("#main is missing or cannot take focus"));
+    }
+
+    // Headings.
+    let levels = elements
+        .iter()
+        .filter_map(Element::heading_level)
+        .collect::<Vec<u8>>();
+    let top_level_count = elements
+        .iter()
+        .filter(|element| element.name == "h1")
+        .count();
+    if top_level_count != 1 {
+        violations.push(format!("the page has {top_level_count} <h1>s"));
+    }
+    if levels.first() != Some(&1) {
+        violations.push(String::from("the first heading is not an <h1>"));
+    }
+    for pair in levels.windows(2) {
+        if pair[1] > pair[0] + 1 {
+            violations.push(format!(
+                "heading skips from <h{}> to <h{}>",
+                pair[0], pair[1]
+            ));
+        }
+    }
+
+    // Names, alt text, labels and icons.
+    let label_targets = elements
+        .iter()
+        .filter(|element| element.name == "label")
+        .filter_map(|label| label.attribute("for"))
+        .collect::<Vec<&str>>();
+
+    for element in elements {
+        match element.name.as_str() {
+            "a" | "button" if !element.has_name() => violations.push(format!(
+                "<{}> has no accessible name: {:?}",
+                element.name, element.attributes
+            )),
+            "img" if element.attribute("alt").is_none() => {
+                violations.push(format!("<img> has no alt text: {:?}", element.attributes));
+            }
+            "input" | "textarea" | "select"
+                if element.attribute("type") != Some("hidden")
+                    && !element.in_label
+                    && element.attribute("aria-label").is_none()
+                    && !element
+                        .attribute("id")
+                        .is_some_and(|id| label_targets.contains(&id)) =>
+            {
+                violations.push(format!(
+                    "<{}> has no label: {:?}",
+                    element.name, element.attributes
+                ));
+            }
+            "i" if element.attribute("aria-hidden") != Some("true") => {
+                violations.push(format!("icon is not aria-hidden: {:?}", element.attributes));
+            }
+            _ => {}
+        }
+    }
+
+    // Unique ids.
+    let mut ids = HashMap::<&str, usize>::new();
+    for id in elements
+        .iter()
+        .filter_map(|element| element.attribute("id"))
+    {
+        *ids.entry(id).or_default() += 1;
+    }
+    for (id, count) in ids {
+        if count > 1 {
+            violations.push(format!("id {id:?} is used {count} times"));
+        }
+    }
+
+    violations
+}
+
+// ********************************** Tests ********************************* //
+// ************************************************************************** //
+
+#[rstest]
+#[case(Route::Home)]
+#[case(Route::Skills)]
+#[case(Route::Achievements)]
+#[case(Route::Creations)]
+#[case(Route::Projects)]
+#[case(Route::Articles)]
+#[case(Route::Education)]
+#[case(Route::Experience)]
+#[case(Route::Contact)]
+#[case(Route::Cv)]
+#[case(Route::NotFound)]
+#[tokio::test]
+async fn page_passes_a11y_rules(#[case] route: Route) {
+    let html = render(&route).await;
+
+    assert_eq!(violations(&parse(&html)), Vec::<String>::new(), "{html}");
+}
+
+#[rstest]
+#[tokio::test]
+async fn content_pages_pass_a11y_rules() {
+    for route in content_routes() {
+        let html = render(&route).await;
+
+        assert_eq!(
+            violations(&parse(&html)),
+            Vec::<String>::new(),
+            "{}: {html}",
+            route.to_path()
+        );
+    }
+}
+
+#[rstest]
+#[case("<a href=\"#main\"></a>", "<a> has no accessible name")]
+#[case(
+    "<a href=\"#main\"><i class=\"fa\"></i></a>",
+    "icon is not aria-hidden"
+)]
+#[case("<h1>A</h1><h3>B</h3>", "heading skips from <h1> to <h3>")]
+#[case("<h1>A</h1><h1>B</h1>", "the page has 2 <h1>s")]
+#[case("<img src=\"a.png\">", "<img> has no alt text")]
+#[case("<input id=\"a\" type=\"text\">", "<input> has no label")]
+#[case("<p id=\"a\"></p><p id=\"a\"></p>", "id \"a\" is used 2 times")]
+fn rules_catch_violations(#[case] html: &str, #[case] expected: &str) {
+    let violations = violations(&parse(html));
+
+    assert!(
+        violations
+            .iter()
+            .any(|violation| violation.starts_with(expected)),
+        "{violations:?}"
+    );
+}